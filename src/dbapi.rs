@@ -2,19 +2,33 @@
 // see: [PEP 249](https://www.python.org/dev/peps/pep-0249)
 
 // Standard Library Imports
-use std::borrow::Borrow;
+use std::cmp::{max, min};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
 
 // Third-Party Imports
+use gluesql::core::ast::Statement;
 use gluesql::core::data::Row;
 use gluesql::prelude::*;
 use pyo3;
-use pyo3::exceptions::{PyAttributeError, PyIndexError};
+use pyo3::exceptions::{PyAttributeError, PyIndexError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::types::PySliceIndices;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyLong, PySliceIndices, PyString, PyTuple};
+use pyo3_chrono::{NaiveDate as PyDate, NaiveDateTime as PyDateTime, NaiveTime as PyTime};
+use rust_decimal::Decimal;
 
 // Crate-Level Imports
-use crate::structs::DataFlexDB;
-use crate::{iif, AttrIndexSliceOrItem, ValueOrSlice};
+use crate::enums::{DataType, Encoding};
+use crate::exceptions::{py_err_from_sql, DataError, NotSupportedError, ProgrammingError};
+use crate::options::OpenOptions;
+use crate::sql::{
+    referenced_tables, FunctionKind, FunctionPlan, FunctionRegistry, PreparedStatement,
+    UserFunction,
+};
+use crate::structs::{Column, DataFlexDB, Diagnostic};
+use crate::utils::string_from_path;
+use crate::{iif, AttrIndexSliceOrItem, InterruptFlag, ValueOrSlice};
 
 // <editor-fold desc="// Component Registration ...">
 
@@ -28,9 +42,20 @@ pub(crate) fn register_components(py: Python, ferroflex_module: &PyModule) -> Py
     dbapi_module.add("paramstyle", PARAMSTYLE)?;
     dbapi_module.add("threadsafety", THREADSAFETY)?;
 
+    // Add the type objects
+    dbapi_module.add("STRING", STRING)?;
+    dbapi_module.add("BINARY", BINARY)?;
+    dbapi_module.add("NUMBER", NUMBER)?;
+    dbapi_module.add("DATETIME", DATETIME)?;
+    dbapi_module.add("ROWID", ROWID)?;
+
     // Add the constructor function(s) to the module
     dbapi_module.add_function(pyo3::wrap_pyfunction!(connect, dbapi_module)?)?;
 
+    // Add the built-in row factories
+    dbapi_module.add_function(pyo3::wrap_pyfunction!(dict_factory, dbapi_module)?)?;
+    dbapi_module.add_function(pyo3::wrap_pyfunction!(namedtuple_factory, dbapi_module)?)?;
+
     // Add the class objects to the module
     dbapi_module.add_class::<Cursor>()?;
     dbapi_module.add_class::<Connection>()?;
@@ -71,22 +96,84 @@ fn connect(
     //            be acquired on the target file(s) before raising an error
     // isolation_level - (not currently implemented) the transaction isolation
     //                    style the connection should emulate / use
-    // uri - (not currently implemented) indicates that the string supplied as
-    //       `database` should be interpreted as a URI allowing the user to
-    //        specify additional options.
-
-    Ok(Connection {
-        closed: false,
-        total_changes: 0,
-        in_transaction: false,
+    // uri - indicates that the string supplied as `database` should be
+    //       interpreted as a `file:` URI allowing the user to specify
+    //       additional options (see `OpenOptions::from_uri`).
+
+    let (database, options) = if uri.unwrap_or(false) {
+        OpenOptions::from_uri(&database)?
+    } else {
+        (database, OpenOptions::default())
+    };
+
+    Ok(Connection::new(
+        DataFlexDB::open(database, options)?,
         isolation_level,
-        sql_engine: Glue::new(DataFlexDB::from_path(database)?),
-        results: Vec::new(),
-    })
+    ))
 }
 
 // </editor-fold desc="// Constructors ...">
 
+// <editor-fold desc="// Row Factories ...">
+
+#[pyfunction]
+#[pyo3(text_signature = "(cursor: Cursor, row: Tuple[Any, ...]) -> Dict[str, Any]")]
+/// A `row_factory` that returns each row as a dict keyed by column name
+fn dict_factory(py: Python, cursor: PyRef<Cursor>, row: &PyTuple) -> PyResult<PyObject> {
+    row_as_dict(py, &cursor._column_names(), row)
+}
+
+#[pyfunction]
+#[pyo3(text_signature = "(cursor: Cursor, row: Tuple[Any, ...]) -> NamedTuple")]
+/// A `row_factory` that returns each row as a named tuple whose
+/// fields are named after the result set's columns
+fn namedtuple_factory(
+    py: Python,
+    mut cursor: PyRefMut<Cursor>,
+    row: &PyTuple,
+) -> PyResult<PyObject> {
+    // The named-tuple class is only created once per result set
+    let row_type: PyObject = match &cursor.row_type {
+        Some(row_type) => row_type.clone_ref(py),
+        None => {
+            let row_type: PyObject = namedtuple_type(py, &cursor._column_names())?;
+
+            cursor.row_type = Some(row_type.clone_ref(py));
+            row_type
+        }
+    };
+
+    row_type.call1(py, row)
+}
+
+/// Create a named-tuple class with fields named after `names`
+/// (names that aren't valid identifiers are replaced by
+/// positional names, e.g. `_1`)
+pub(crate) fn namedtuple_type(py: Python, names: &[String]) -> PyResult<PyObject> {
+    let kwargs = PyDict::new(py);
+
+    kwargs.set_item("rename", true)?;
+
+    Ok(py
+        .import("collections")?
+        .getattr("namedtuple")?
+        .call(("Row", names.to_vec()), Some(kwargs))?
+        .to_object(py))
+}
+
+/// Convert a row into a dict keyed by the supplied column names
+pub(crate) fn row_as_dict(py: Python, names: &[String], row: &PyTuple) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+
+    for (name, value) in names.iter().zip(row.iter()) {
+        dict.set_item(name, value)?;
+    }
+
+    Ok(dict.to_object(py))
+}
+
+// </editor-fold desc="// Row Factories ...">
+
 // <editor-fold desc="// Globals ...">
 
 /// String constant stating the supported DB API level.
@@ -114,53 +201,319 @@ pub const PARAMSTYLE: &str = "qmark";
 
 // <editor-fold desc="// Connection ...">
 
-// #[derive(Clone, Debug, Default)]
 #[pyclass(dict, module = "ferroflex.dbapi")]
 /// A standard DB-API v2 Connection object.
 pub struct Connection {
     #[pyo3(get)]
+    /// The current default isolation level
+    pub isolation_level: Option<String>,
+    #[pyo3(get, set)]
+    /// The callable used by new cursors to convert each
+    /// result row, called as `row_factory(cursor, row)`
+    pub row_factory: Option<PyObject>,
     /// Indicates if the connection is open or not
-    pub closed: bool,
+    closed: AtomicBool,
+    /// Indicates that the connection's database
+    /// was opened in read-only mode
+    read_only: bool,
     #[pyo3(get)]
+    /// Every structure that was skipped while the
+    /// connection's database was being loaded
+    pub diagnostics: Vec<Diagnostic>,
     /// The total number of database rows that have
     /// been modified, inserted, or deleted since the
     /// database connection was opened.
-    pub total_changes: usize,
-    #[pyo3(get)]
+    total_changes: AtomicUsize,
     /// Indicates the whether or not the connection
     /// currently has uncommitted changes (i.e. an
     /// active transaction).
-    pub in_transaction: bool,
-    #[pyo3(get)]
-    /// The current default isolation level
-    pub isolation_level: Option<String>,
-    /// The connection's GlueSQL engine
-    sql_engine: Glue<usize, DataFlexDB>,
-    /// The results of the connection's most recently
-    /// executed query
-    results: Vec<Row>,
+    in_transaction: AtomicBool,
+    /// The flag shared with the connection's database
+    /// that cancels any scan currently in progress
+    interrupt: InterruptFlag,
+    /// The encoding of the database's text, used to decode
+    /// `bytes` parameters and function results
+    pub(crate) encoding: Encoding,
+    /// The connection's GlueSQL engine (dropped
+    /// when the connection is closed)
+    sql_engine: Mutex<Option<Glue<usize, DataFlexDB>>>,
+    /// The user-defined functions and aggregates
+    /// registered against the connection
+    functions: Mutex<FunctionRegistry>,
 }
 
 unsafe impl Send for Connection {}
 
+impl Connection {
+    pub(crate) fn new(database: DataFlexDB, isolation_level: Option<String>) -> Connection {
+        Connection {
+            isolation_level,
+            row_factory: None,
+            closed: AtomicBool::new(false),
+            read_only: database.options.read_only,
+            diagnostics: database.diagnostics(),
+            total_changes: AtomicUsize::new(0),
+            in_transaction: AtomicBool::new(false),
+            interrupt: database.interrupt_flag(),
+            encoding: database.options.encoding.clone(),
+            sql_engine: Mutex::new(Some(Glue::new(database))),
+            functions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Raise a `ProgrammingError` if the connection has been closed
+    pub(crate) fn _ensure_open(&self) -> PyResult<()> {
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(ProgrammingError::new_err(
+                "Cannot operate on a closed connection.",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Raise a `NotSupportedError` for transaction control that
+    /// can't be honoured by a read-write connection
+    fn _ensure_read_only(&self, operation: &str) -> PyResult<()> {
+        if !self.read_only {
+            return Err(NotSupportedError::new_err(format!(
+                "Cannot {} on a read-write connection: DataFlex tables aren't transactional.",
+                operation
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Lock the connection's SQL engine, recovering it
+    /// if a previous holder of the lock panicked
+    fn _engine(&self) -> MutexGuard<'_, Option<Glue<usize, DataFlexDB>>> {
+        match self.sql_engine.lock() {
+            Ok(engine) => engine,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Run the supplied SQL against the connection's engine.
+    /// The GIL is released while the statement executes so
+    /// that other threads can call `interrupt()`.
+    pub(crate) fn _execute(&self, py: Python, sql: &str) -> PyResult<Payload> {
+        self._ensure_open()?;
+        self.interrupt.clear();
+
+        py.allow_threads(|| match self._engine().as_mut() {
+            None => Err(ProgrammingError::new_err(
+                "Cannot operate on a closed connection.",
+            )),
+            Some(engine) => engine.execute(sql).map_err(py_err_from_sql),
+        })
+    }
+
+    /// Execute an already-parsed statement
+    pub(crate) fn _execute_statement(&self, py: Python, statement: Statement) -> PyResult<Payload> {
+        self._ensure_open()?;
+        self.interrupt.clear();
+
+        py.allow_threads(|| match self._engine().as_mut() {
+            None => Err(ProgrammingError::new_err(
+                "Cannot operate on a closed connection.",
+            )),
+            Some(engine) => engine.execute_stmt(statement).map_err(py_err_from_sql),
+        })
+    }
+
+    /// Describe the columns of a result set using the DataFlex
+    /// column definitions of the tables referenced by `sql`
+    pub(crate) fn _describe(
+        &self,
+        sql: &str,
+        labels: &[String],
+        sample: Option<&Row>,
+    ) -> Vec<CursorDescription> {
+        let tables: Vec<String> = referenced_tables(sql);
+        let engine = self._engine();
+        let db = engine.as_ref().and_then(|engine| engine.storage.as_ref());
+
+        labels
+            .iter()
+            .enumerate()
+            .map(
+                |(idx, label)| match db.and_then(|db| db.find_column(&tables, label)) {
+                    Some(column) => CursorDescription::from_column(label, column),
+                    None => {
+                        CursorDescription::from_value(label, sample.and_then(|row| row.0.get(idx)))
+                    }
+                },
+            )
+            .collect()
+    }
+
+    /// Rewrite the supplied SQL (if needed) so that any calls it
+    /// makes to user-defined functions can be evaluated
+    pub(crate) fn _plan_functions(&self, py: Python, sql: &str) -> PyResult<Option<FunctionPlan>> {
+        let functions = match self.functions.lock() {
+            Ok(functions) => functions,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let execute = |sql: &str| -> PyResult<Vec<Vec<Value>>> {
+            match self._execute(py, sql)? {
+                Payload::Select { rows, .. } => Ok(rows),
+                _ => Ok(Vec::new()),
+            }
+        };
+
+        FunctionPlan::new(py, sql, &functions, &execute)
+    }
+
+    /// Indicates that the supplied SQL calls any user-defined functions
+    pub(crate) fn _calls_functions(&self, sql: &str) -> bool {
+        let functions = match self.functions.lock() {
+            Ok(functions) => functions,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        FunctionPlan::calls_any(sql, &functions)
+    }
+
+    /// Register (or, if `callable` is `None`, remove) a user-defined function
+    fn _register_function(
+        &self,
+        name: &str,
+        num_params: i32,
+        kind: FunctionKind,
+        callable: &PyAny,
+    ) -> PyResult<()> {
+        self._ensure_open()?;
+
+        let mut functions = match self.functions.lock() {
+            Ok(functions) => functions,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if callable.is_none() {
+            functions.remove(&name.to_uppercase());
+            return Ok(());
+        }
+
+        if !callable.is_callable() {
+            return Err(PyTypeError::new_err(format!(
+                "the function registered as '{}' must be callable",
+                name
+            )));
+        }
+
+        if num_params < -1 {
+            return Err(ProgrammingError::new_err(format!(
+                "invalid number of parameters for '{}': {}",
+                name, num_params
+            )));
+        }
+
+        functions.insert(
+            name.to_uppercase(),
+            UserFunction {
+                name: name.to_string(),
+                num_params,
+                kind,
+                callable: callable.into(),
+                encoding: self.encoding.clone(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Add the supplied number of modified rows to the connection's total
+    pub(crate) fn _record_changes(&self, count: usize) {
+        self.total_changes.fetch_add(count, Ordering::SeqCst);
+    }
+}
+
 #[allow(unused_variables)]
 #[pymethods]
 impl Connection {
+    // <editor-fold desc="// Magic methods ...">
+
+    /// Use the connection as a context manager
+    fn __enter__(slf: PyRef<Self>) -> PyResult<PyRef<Self>> {
+        slf._ensure_open()?;
+
+        Ok(slf)
+    }
+
+    /// Leave the context manager, committing if the block completed
+    /// and rolling back if it raised (like `sqlite3`). The connection
+    /// isn't closed.
+    ///
+    /// Changes made through a read-write connection can't be rolled
+    /// back, but that mustn't hide the exception that's propagating,
+    /// so the rollback's `NotSupportedError` is ignored.
+    fn __exit__(
+        &self,
+        py: Python,
+        exc_type: Option<&PyAny>,
+        exc_value: Option<&PyAny>,
+        traceback: Option<&PyAny>,
+    ) -> PyResult<bool> {
+        if exc_type.is_none() {
+            self.commit()?;
+        } else {
+            match self.rollback() {
+                Err(error) if error.is_instance::<NotSupportedError>(py) => {}
+                result => result?,
+            }
+        }
+
+        Ok(false)
+    }
+
+    // </editor-fold desc="// Magic methods ...">
+
+    #[getter]
+    /// Indicates if the connection is open or not
+    fn closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+
+    #[getter]
+    /// The total number of database rows that have
+    /// been modified, inserted, or deleted since the
+    /// database connection was opened.
+    fn total_changes(&self) -> usize {
+        self.total_changes.load(Ordering::SeqCst)
+    }
+
+    #[getter]
+    /// Indicates if the connection can't modify its database
+    fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    #[getter]
+    /// Indicates the whether or not the connection
+    /// currently has uncommitted changes (i.e. an
+    /// active transaction).
+    fn in_transaction(&self) -> bool {
+        self.in_transaction.load(Ordering::SeqCst)
+    }
+
     #[pyo3(text_signature = "($self) -> str")]
     /// The path of the DataFlex "database" being
     /// connected to
     fn db_path(&self) -> PyResult<String> {
-        Ok(self
-            .sql_engine
-            .borrow()
-            .storage
+        self._ensure_open()?;
+
+        match self
+            ._engine()
             .as_ref()
-            .unwrap()
-            .borrow()
-            .db_path
-            .to_str()
-            .unwrap()
-            .to_string())
+            .and_then(|engine| engine.storage.as_ref())
+        {
+            Some(db) => Ok(string_from_path(&db.db_path, Some(false))),
+            None => Err(ProgrammingError::new_err(
+                "Cannot operate on a closed connection.",
+            )),
+        }
     }
 
     #[pyo3(text_signature = "($self) -> None")]
@@ -170,8 +523,22 @@ impl Connection {
     // same applies to all cursor objects trying to use the connection. Note
     // that closing a connection without committing the changes first will
     // cause an implicit rollback to be performed.
-    fn close(&mut self) -> PyResult<()> {
-        todo!()
+    fn close(&self, py: Python) -> PyResult<()> {
+        // Stop anything that's still running so
+        // that the engine's lock can be acquired
+        self.interrupt.set();
+        self.closed.store(true, Ordering::SeqCst);
+
+        // Dropping the engine drops the underlying `DataFlexDB`,
+        // which in turn releases every file handle the
+        // connection was holding
+        py.allow_threads(|| {
+            self._engine().take();
+        });
+
+        self.in_transaction.store(false, Ordering::SeqCst);
+
+        Ok(())
     }
 
     #[pyo3(text_signature = "($self) -> None")]
@@ -179,60 +546,111 @@ impl Connection {
     /// If this method is not called, the results of any query
     /// executed since the last call to `commit()` will not visible
     /// from other connection.
-    fn commit(&mut self) -> PyResult<()> {
-        todo!()
+    ///
+    /// DataFlex tables aren't transactional, so changes made through
+    /// a read-write connection are written through as each statement
+    /// executes (i.e. the connection is always in autocommit mode)
+    /// and committing is a no-op.
+    fn commit(&self) -> PyResult<()> {
+        self._ensure_open()
     }
 
     #[pyo3(text_signature = "($self) -> None")]
     /// Rolls back any changes to the database since the last
     /// call to `commit()`.
-    fn rollback(&mut self) -> PyResult<()> {
-        todo!()
+    ///
+    /// Read-only connections can't make any changes, so rolling
+    /// back is a no-op. DataFlex tables aren't transactional, so
+    /// changes made through a read-write connection can't be undone,
+    /// and a `NotSupportedError` is raised.
+    fn rollback(&self) -> PyResult<()> {
+        self._ensure_open()?;
+        self._ensure_read_only("rollback")
     }
 
     #[pyo3(text_signature = "($self) -> Cursor")]
     /// Create a new `Cursor` object using the connection.
-    fn cursor(&mut self) -> PyResult<Cursor> {
-        todo!()
+    fn cursor(slf: PyRef<Self>) -> PyResult<Cursor> {
+        slf._ensure_open()?;
+
+        let row_factory: Option<PyObject> = slf
+            .row_factory
+            .as_ref()
+            .map(|factory| factory.clone_ref(slf.py()));
+
+        Ok(Cursor::new(slf.into(), row_factory))
     }
 
     #[pyo3(text_signature = "($self) -> None")]
     /// Abort any queries that might be executing on the connection.
     /// The query will then abort and the caller will get an exception.
-    fn interrupt(&mut self) -> PyResult<()> {
-        todo!()
+    fn interrupt(&self) -> PyResult<()> {
+        self._ensure_open()?;
+        self.interrupt.set();
+
+        Ok(())
     }
 
-    #[pyo3(text_signature = "($self) -> Optional[Sequence[CursorDescription]]")]
-    /// Prepare and execute a database operation (query or command).
-    /// Parameters may be provided as sequence or mapping and will
-    /// be bound to variables in the operation.
-    fn execute(&mut self, sql: &str, parameters: Option<Vec<&PyAny>>) -> PyResult<String> {
-        // let output = glue.execute(sql).unwrap();
-        let result = self.sql_engine.execute(sql).unwrap();
-        Ok(format!("{result:?}"))
+    #[pyo3(
+        text_signature = "($self, sql: str, parameters: Optional[Sequence[Any]] = None) -> Cursor"
+    )]
+    /// Create a new `Cursor`, then prepare and execute a database
+    /// operation (query or command) with it. Parameters may be
+    /// provided as a sequence and will be bound to the `?`
+    /// placeholders in the operation.
+    fn execute(
+        slf: PyRef<Self>,
+        py: Python,
+        sql: &str,
+        parameters: Option<Vec<&PyAny>>,
+    ) -> PyResult<Py<Cursor>> {
+        let cursor: Py<Cursor> = Py::new(py, Connection::cursor(slf)?)?;
+
+        Cursor::execute(cursor.borrow_mut(py), py, sql, parameters)?;
+
+        Ok(cursor)
     }
 
-    #[pyo3(text_signature = "($self) -> Optional[Sequence[Sequence[CursorDescription]]]")]
-    /// Prepare a database operation (query or command) and then
-    /// execute it against all parameter sets supplied by the
-    /// `parameters` argument.
-    fn executemany(&mut self, sql: &str, parameters: Option<Vec<Vec<&PyAny>>>) -> PyResult<()> {
-        todo!()
+    #[pyo3(text_signature = "($self, sql: str, parameters: Sequence[Sequence[Any]]) -> Cursor")]
+    /// Create a new `Cursor`, then prepare a database operation
+    /// (query or command) and execute it against all parameter
+    /// sets supplied by the `parameters` argument.
+    fn executemany(
+        slf: PyRef<Self>,
+        py: Python,
+        sql: &str,
+        parameters: Option<Vec<Vec<&PyAny>>>,
+    ) -> PyResult<Py<Cursor>> {
+        let cursor: Py<Cursor> = Py::new(py, Connection::cursor(slf)?)?;
+
+        Cursor::executemany(
+            cursor.borrow_mut(py),
+            py,
+            sql,
+            parameters.unwrap_or_default(),
+        )?;
+
+        Ok(cursor)
     }
 
-    #[pyo3(text_signature = "($self) -> None")]
+    #[pyo3(text_signature = "($self, name, num_params, func) -> None")]
     /// Create a user-defined function that can be used from
     /// within SQL statements under the function name `name`.
     /// The `num_params` argument is the number of parameters
     /// the function accepts (if num_params is -1, the function
     /// may take any number of arguments), and `func` is a
     /// Python callable that is called as the SQL function.
-    fn create_function(&mut self, name: &str, num_params: u8, func: &PyAny) -> PyResult<()> {
-        todo!()
+    /// Passing `None` as `func` removes the function.
+    ///
+    /// Calls whose arguments are all literals can be used
+    /// anywhere in a statement. Other calls can be used anywhere
+    /// in a query (e.g. in its WHERE or ORDER BY clauses) that
+    /// doesn't contain subqueries.
+    fn create_function(&self, name: &str, num_params: i32, func: &PyAny) -> PyResult<()> {
+        self._register_function(name, num_params, FunctionKind::Scalar, func)
     }
 
-    #[pyo3(text_signature = "($self) -> None")]
+    #[pyo3(text_signature = "($self, name, num_params, aggregate_class) -> None")]
     /// Create a user-defined aggregate function.
     /// The specified aggregate class must implement a `step`
     /// method, which accepts the number of parameters `num_params`
@@ -241,14 +659,14 @@ impl Connection {
     /// final result of the aggregate.
     /// The `finalize` method can return any of the types supported
     /// by DataFlex: bytes, str, int, float, date, and None.
+    /// Passing `None` as `aggregate_class` removes the aggregate.
     fn create_aggregate(
-        &mut self,
+        &self,
         name: &str,
-        num_params: u8,
+        num_params: i32,
         aggregate_class: &PyAny,
     ) -> PyResult<()> {
-        // Ok(())
-        todo!()
+        self._register_function(name, num_params, FunctionKind::Aggregate, aggregate_class)
     }
 }
 
@@ -293,6 +711,59 @@ pub struct CursorDescription {
 unsafe impl Send for CursorDescription {}
 
 impl CursorDescription {
+    /// Describe a result column using its DataFlex column definition
+    pub(crate) fn from_column<T: AsRef<str>>(name: T, column: &Column) -> CursorDescription {
+        let length: usize = column.length as usize;
+
+        let (type_code, display_size, precision, scale) = match column.data_type {
+            DataType::Ascii | DataType::Text => (STRING, Some(length), None, None),
+            DataType::Date => (DATETIME, Some(10usize), None, None),
+            DataType::Int | DataType::Float => {
+                // Each byte of a BCD-encoded value holds two digits
+                let (precision, scale) = (length * 2, column.decimal_points as usize);
+
+                (
+                    NUMBER,
+                    // Leave room for the sign and (if needed) the decimal point
+                    Some(precision + 1 + iif!(scale > 0, 1, 0)),
+                    Some(precision),
+                    Some(scale),
+                )
+            }
+            DataType::Binary | DataType::Unknown => (BINARY, None, None, None),
+        };
+
+        CursorDescription {
+            name: name.as_ref().to_string(),
+            type_code,
+            display_size,
+            internal_size: Some(length),
+            precision,
+            scale,
+            // Only "empty" dates and unsupported
+            // types are ever reported as NULL
+            null_ok: Some(matches!(
+                column.data_type,
+                DataType::Date | DataType::Binary | DataType::Unknown
+            )),
+        }
+    }
+
+    /// Describe a result column that doesn't map to a DataFlex column
+    /// (e.g. a computed expression) using a sample of its values
+    pub(crate) fn from_value<T: AsRef<str>>(name: T, value: Option<&Value>) -> CursorDescription {
+        CursorDescription {
+            name: name.as_ref().to_string(),
+            type_code: match value {
+                Some(Value::Bool(_) | Value::I8(_) | Value::I64(_) | Value::F64(_)) => NUMBER,
+                Some(Value::Decimal(_)) => NUMBER,
+                Some(Value::Date(_) | Value::Timestamp(_) | Value::Time(_)) => DATETIME,
+                _ => STRING,
+            },
+            ..CursorDescription::default()
+        }
+    }
+
     fn _get_item(&self, value: AttrIndexSliceOrItem<isize>) -> PyResult<ValueOrSlice<PyObject>> {
         Python::with_gil(|py| match value {
             AttrIndexSliceOrItem::Item(idx) | AttrIndexSliceOrItem::Index(idx) => {
@@ -352,6 +823,11 @@ impl CursorDescription {
 
 #[pymethods]
 impl CursorDescription {
+    /// The number of fields in a `CursorDescription`
+    fn __len__(&self) -> usize {
+        7
+    }
+
     /// Get a `CursorDescription` field by numerical index
     fn __getitem__(
         slf: PyRefMut<Self>,
@@ -359,6 +835,25 @@ impl CursorDescription {
     ) -> PyResult<ValueOrSlice<PyObject>> {
         slf._get_item(value)
     }
+
+    /// Iterate over the `CursorDescription`'s fields (so that
+    /// it can be unpacked like any other 7-item sequence)
+    fn __iter__(&self, py: Python) -> PyResult<PyObject> {
+        let fields: &PyTuple = PyTuple::new(
+            py,
+            [
+                self.name.to_object(py),
+                self.type_code.to_object(py),
+                self.display_size.to_object(py),
+                self.internal_size.to_object(py),
+                self.precision.to_object(py),
+                self.scale.to_object(py),
+                self.null_ok.to_object(py),
+            ],
+        );
+
+        Ok(fields.call_method0("__iter__")?.to_object(py))
+    }
 }
 
 // </editor-fold desc="// Cursor Description ...">
@@ -394,14 +889,165 @@ pub struct Cursor {
     /// return rows or if the cursor has not had an operation
     /// invoked via the .execute*() method yet.
     pub description: Option<Vec<CursorDescription>>,
-    // #[pyo3(get)]
-    // /// A reference to the Connection object on which the
-    // /// cursor was created.
-    // pub connection: &'conn Connection,
+    #[pyo3(get)]
+    /// A reference to the Connection object on which the
+    /// cursor was created.
+    pub connection: Py<Connection>,
+    #[pyo3(get, set)]
+    /// The callable used to convert each result row, called as
+    /// `row_factory(cursor, row)`. Defaults to the connection's
+    /// `row_factory` at the time the cursor was created.
+    pub row_factory: Option<PyObject>,
+    /// The named-tuple class used by `namedtuple_factory`
+    /// for the cursor's current result set
+    row_type: Option<PyObject>,
+    /// Indicates if the cursor is open or not
+    closed: bool,
+    /// The not-yet-fetched rows produced by the
+    /// cursor's most recently executed query
+    results: VecDeque<Row>,
 }
 
 unsafe impl Send for Cursor {}
 
+impl Cursor {
+    pub(crate) fn new(connection: Py<Connection>, row_factory: Option<PyObject>) -> Cursor {
+        Cursor {
+            rowcount: Some(-1),
+            lastrowid: None,
+            arraysize: Some(1),
+            description: None,
+            connection,
+            row_factory,
+            row_type: None,
+            closed: false,
+            results: VecDeque::new(),
+        }
+    }
+
+    /// Raise a `ProgrammingError` if either the cursor
+    /// or its parent connection have been closed
+    fn _ensure_open(&self, py: Python) -> PyResult<()> {
+        if self.closed {
+            return Err(ProgrammingError::new_err(
+                "Cannot operate on a closed cursor.",
+            ));
+        }
+
+        self.connection.borrow(py)._ensure_open()
+    }
+
+    /// Raise a `ProgrammingError` if the cursor's most
+    /// recent operation didn't produce a result set
+    fn _ensure_result_set(&self, py: Python) -> PyResult<()> {
+        self._ensure_open(py)?;
+
+        if self.description.is_none() {
+            return Err(ProgrammingError::new_err(
+                "The previous operation did not produce a result set.",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Reset the cursor's state ahead of a new operation
+    fn _reset(&mut self) {
+        self.rowcount = Some(-1);
+        self.lastrowid = None;
+        self.description = None;
+        self.row_type = None;
+        self.results.clear();
+    }
+
+    /// Execute a single (fully bound) operation
+    /// and load its results into the cursor
+    pub(crate) fn _execute(&mut self, py: Python, operation: &str) -> PyResult<()> {
+        self._ensure_open(py)?;
+        self._reset();
+
+        let connection = self.connection.borrow(py);
+        let plan: Option<FunctionPlan> = connection._plan_functions(py, operation)?;
+        let sql: &str = plan.as_ref().map_or(operation, |plan| plan.sql.as_str());
+
+        match connection._execute(py, sql)? {
+            Payload::Select { labels, rows } => {
+                let rows: Vec<Row> = rows.into_iter().map(Row).collect();
+                let (labels, rows) = match &plan {
+                    Some(plan) => plan.apply(py, labels, rows)?,
+                    None => (labels, rows),
+                };
+
+                self.description = Some(connection._describe(sql, &labels, rows.first()));
+                self.rowcount = Some(rows.len() as isize);
+                self.results = rows.into();
+            }
+            Payload::Insert(count) | Payload::Update(count) | Payload::Delete(count) => {
+                connection._record_changes(count);
+                self.rowcount = Some(count as isize);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Execute a single (fully bound) data-modifying statement
+    fn _execute_parsed(&mut self, py: Python, statement: Statement) -> PyResult<()> {
+        self._ensure_open(py)?;
+        self._reset();
+
+        let connection = self.connection.borrow(py);
+
+        match connection._execute_statement(py, statement)? {
+            Payload::Insert(count) | Payload::Update(count) | Payload::Delete(count) => {
+                connection._record_changes(count);
+                self.rowcount = Some(count as isize);
+            }
+            // Flag the result set so the caller rejects the statement
+            Payload::Select { labels, .. } => {
+                self.description = Some(connection._describe("", &labels, None));
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Pop (up to) `size` rows off of the cursor's results, passing
+    /// each through the cursor's `row_factory` (if it has one).
+    /// The cursor's borrow is released before the factory is called
+    /// so that the factory is free to inspect the cursor.
+    fn _fetch(mut slf: PyRefMut<Self>, py: Python, size: Option<usize>) -> PyResult<Vec<PyObject>> {
+        slf._ensure_result_set(py)?;
+
+        let count: usize = min(size.unwrap_or(usize::MAX), slf.results.len());
+        let rows: Vec<Row> = slf.results.drain(..count).collect();
+        let factory: Option<PyObject> = slf.row_factory.as_ref().map(|f| f.clone_ref(py));
+        let cursor: Py<Cursor> = slf.into();
+
+        rows.iter()
+            .map(|row| {
+                let row: PyObject = py_from_row(py, row)?;
+
+                match &factory {
+                    Some(factory) => factory.call1(py, (cursor.clone_ref(py), row)),
+                    None => Ok(row),
+                }
+            })
+            .collect()
+    }
+
+    /// The names of the columns in the cursor's current result set
+    fn _column_names(&self) -> Vec<String> {
+        self.description
+            .iter()
+            .flatten()
+            .map(|column| column.name.clone())
+            .collect()
+    }
+}
+
 #[allow(unused_variables)]
 #[pymethods]
 impl Cursor {
@@ -429,212 +1075,179 @@ impl Cursor {
 
     // <editor-fold desc="// Magic methods ...">
 
-    //     def __init__(self) -> None:
-    //         pass
-    //
-    //     def __iter__(self) -> Any:
-    //         return NotImplemented
+    /// Iterate over the rows of the cursor's current result set
+    fn __iter__(slf: PyRef<Self>) -> PyResult<PyRef<Self>> {
+        slf._ensure_result_set(slf.py())?;
 
-    // #[args(args = "*")]
-    // #[pyo3(text_signature = "($self, *args: Optional[Any]) -> None")]
-    // /// Call the `Cursor` class directly
-    // fn __call__(&self, args: &PyTuple) -> PyResult<i32> {
-    //     println!("Cursor class has been called");
-    //     Ok(0)
-    // }
+        Ok(slf)
+    }
 
-    // </editor-fold desc="// Magic methods ...">
+    /// Get the next row of the cursor's current result set
+    fn __next__(slf: PyRefMut<Self>, py: Python) -> PyResult<Option<PyObject>> {
+        Ok(Cursor::_fetch(slf, py, Some(1))?.pop())
+    }
 
-    // <editor-fold desc="// Required Properties ...">
-    //
-    // @property
-    // def description(self) -> Sequence[Any]:
-    //     """This read-only attribute is a sequence of 7-item sequences.
-    //
-    //     Each of these sequences contains information describing one result column:
-    //
-    //     - name
-    //     - type_code
-    //     - display_size
-    //     - internal_size
-    //     - precision
-    //     - scale
-    //     - null_ok
-    //
-    //     The first two items (name and type_code) are mandatory, the other five are optional
-    //     and are set to None if no meaningful values can be provided. This attribute will
-    //     be None for operations that do not return rows or if the cursor has not had an
-    //     operation invoked via the .execute() method yet.
-    //     """
-    //     return NotImplemented
-    //
-    // @property
-    // def rowcount(self) -> int:
-    //     """This read-only attribute specifies the number of rows that the last
-    //     .execute() produced (for DQL statements like SELECT) or affected (for
-    //     DML statements like UPDATE or INSERT).
-    //
-    //     The attribute is -1 in case no .execute() has been performed on
-    //     the cursor or the rowcount of the last operation is cannot be
-    //     determined by the interface.
-    //     """
-    //     return NotImplemented
+    /// Use the cursor as a context manager
+    fn __enter__(slf: PyRef<Self>) -> PyResult<PyRef<Self>> {
+        slf._ensure_open(slf.py())?;
 
-    // # </editor-fold desc="// Required Properties ...">
+        Ok(slf)
+    }
 
-    // # <editor-fold desc="// 'Optional' properties ...">
+    /// Close the cursor on leaving the context manager
+    fn __exit__(
+        &mut self,
+        exc_type: Option<&PyAny>,
+        exc_value: Option<&PyAny>,
+        traceback: Option<&PyAny>,
+    ) -> PyResult<bool> {
+        self.close()?;
 
-    // # </editor-fold desc="// 'Optional' properties ...">
+        Ok(false)
+    }
+
+    // </editor-fold desc="// Magic methods ...">
 
     // <editor-fold desc="// Required methods ...">
 
-    // def close(self) -> Any:
-    //     """Close the cursor now (rather than whenever __del__ is called).
-    //
-    //     The cursor will be unusable from this point forward; an
-    //     exception will be raised if any operation is attempted with the
-    //     cursor.
-    //     """
-    //     pass
-    //
-    // def execute(self, query: str, parameters: Sequence[Any]) -> Any:
-    //     """Prepare and execute a database operation (query or command).
-    //
-    //     Parameters may be provided as sequence or mapping and will be bound to variables in the
-    //     operation. Variables are specified in a database-specific notation (see the module's paramstyle
-    //     attribute for details).
-    //
-    //     A reference to the operation will be retained by the cursor. If the same operation object
-    //     is passed in again, then the cursor can optimize its behavior. This is most effective for
-    //     algorithms where the same operation is used, but different parameters are bound to it (many times).
-    //
-    //     For maximum efficiency when reusing an operation, it is best to use the .setinputsizes()
-    //     method to specify the parameter types and sizes ahead of time. It is legal for a parameter
-    //     to not match the predefined information; the implementation should compensate, possibly
-    //     with a loss of efficiency.
-    //
-    //     The parameters may also be specified as list of tuples to e.g. insert multiple rows in a
-    //     single operation, but this kind of usage is deprecated: .executemany() should be used instead.
-    //
-    //     Return values are not defined.
-    //     """
-    //     pass
-    //
-    // def executemany(self, query: str, parameters: Sequence[Sequence[Any]]) -> Any:
-    //     """Prepare a database operation (query or command) and then execute it
-    //     against all parameter sequences or mappings found in the sequence
-    //     seq_of_parameters.
-    //
-    //     Modules are free to implement this method using multiple calls to the .execute() method or by
-    //     using array operations to have the database process the sequence as a whole in one call.
-    //
-    //     Use of this method for an operation which produces one or more result sets constitutes undefined
-    //     behavior, and the implementation is permitted (but not required) to raise an exception when it
-    //     detects that a result set has been created by an invocation of the operation.
-    //
-    //     The same comments as for .execute() also apply accordingly to this method.
-    //
-    //     Return values are not defined.
-    //     """
-    //     pass
-    //
-    // def fetchone(self) -> Any:
-    //     """Fetch the next row of a query result set, returning a single
-    //     sequence, or None when no more data is available.
-    //
-    //     An exception is raised if the previous call to .execute() did
-    //     not produce any result set or no call was issued yet.
-    //     """
-    //     pass
-    //
-    // def fetchmany(self, size: Optional[int] = None) -> Any:
-    //     """Fetch the next set of rows of a query result, returning a sequence
-    //     of sequences (e.g. a list of tuples). An empty sequence is returned
-    //     when no more rows are available.
-    //
-    //     The number of rows to fetch per call is specified by the parameter. If it is not given, the cursor's
-    //     arraysize determines the number of rows to be fetched. The method should try to fetch as many rows
-    //     as indicated by the size parameter. If this is not possible due to the specified number of rows not
-    //     being available, fewer rows may be returned.
-    //
-    //     An Error (or subclass) exception is raised if the previous call to .execute*() did not produce any
-    //     result set or no call was issued yet.
-    //
-    //     Note there are performance considerations involved with the size parameter. For optimal performance,
-    //     it is usually best to use the .arraysize attribute. If the size parameter is used, then it is best
-    //     for it to retain the same value from one .fetchmany() call to the next.
-    //     """
-    //     pass
-    //
-    // def fetchall(self) -> Any:
-    //     """Fetch all (remaining) rows of a query result, returning them as a
-    //     sequence of sequences (e.g. a list of tuples).
-    //
-    //     Note that the cursor's arraysize attribute can affect the
-    //     performance of this operation. An exception is raised if the
-    //     previous call to .execute*() did not produce any result set or
-    //     no call was issued yet.
-    //     """
-    //     pass
-    //
-    // def nextset(self) -> Any:
-    //     """This method will make the cursor skip to the next available set,
-    //     discarding any remaining rows from the current set.
-    //
-    //     If there are no more sets, the method returns None. Otherwise, it returns a true value and
-    //     subsequent calls to the .fetch*() methods will return rows from the next result set.
-    //
-    //     An Error (or subclass) exception is raised if the previous call to .execute*() did not
-    //     produce any result set or no call was issued yet.
-    //     """
-    //     pass
-    //
-    // @property
-    // def arraysize(self) -> int:
-    //     """This read/write attribute specifies the number of rows to fetch at a
-    //     time with .fetchmany().
-    //
-    //     It defaults to 1 meaning to fetch a single row at a time.
-    //     Implementations must observe this value with respect to the
-    //     .fetchmany() method, but are free to interact with the database
-    //     a single row at a time. It may also be used in the
-    //     implementation of .executemany().
-    //     """
-    //     return NotImplemented
-    //
-    // @arraysize.setter
-    // def arraysize(self, value: int) -> None:
-    //     """"""
-    //     raise NotImplementedError
-    //
-    // def setinputsizes(self, sizes: int) -> Any:
-    //     """This can be used before a call to .execute*() to predefine memory
-    //     areas for the operation's parameters.
-    //
-    //     `sizes` is specified as a sequence — one item for each input parameter. The item should be a Type Object
-    //     that corresponds to the input that will be used, or it should be an integer specifying the maximum length
-    //     of a string parameter. If the item is None, then no predefined memory area will be reserved for that column
-    //     (this is useful to avoid predefined areas for large inputs).
-    //
-    //     This method would be used before the .execute() method is invoked.
-    //
-    //     Implementations are free to have this method do nothing and users are free to not use it.
-    //     """
-    //     pass
-    //
-    // def setoutputsize(self, size: int, column: Optional[Any] = None) -> Any:
-    //     """Set a column buffer size for fetches of large columns (e.g. LONGs,
-    //     BLOBs, etc.).
-    //
-    //     The column is specified as an index into the result sequence. Not specifying the column will set the
-    //     default size for all large columns in the cursor.
-    //
-    //     This method would be used before the .execute*() method is invoked.
-    //
-    //     Implementations are free to have this method do nothing and users are free to not use it.
-    //     """
-    //     pass
-    //
+    #[pyo3(text_signature = "($self) -> None")]
+    /// Close the cursor now (rather than whenever `__del__` is called).
+    /// The cursor will be unusable from this point forward; an
+    /// exception will be raised if any operation is attempted with
+    /// the cursor.
+    fn close(&mut self) -> PyResult<()> {
+        self._reset();
+        self.closed = true;
+
+        Ok(())
+    }
+
+    #[pyo3(
+        text_signature = "($self, operation: str, parameters: Optional[Sequence[Any]] = None) -> Cursor"
+    )]
+    /// Prepare and execute a database operation (query or command).
+    /// Parameters may be provided as a sequence and will be bound
+    /// to the `?` placeholders in the operation.
+    fn execute<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+        operation: &str,
+        parameters: Option<Vec<&PyAny>>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let encoding: Encoding = slf.connection.borrow(py).encoding.clone();
+        let operation: String =
+            PreparedStatement::from(operation).bind(&parameters.unwrap_or_default(), &encoding)?;
+
+        slf._execute(py, &operation)?;
+
+        Ok(slf)
+    }
+
+    #[pyo3(
+        text_signature = "($self, operation: str, seq_of_parameters: Sequence[Sequence[Any]]) -> Cursor"
+    )]
+    /// Prepare a database operation (query or command) and then
+    /// execute it against all parameter sequences found in
+    /// `seq_of_parameters`. Operations that produce a result set
+    /// are not allowed.
+    ///
+    /// The operation is parsed once and each parameter set is bound
+    /// into a copy of the parsed statement, except for operations
+    /// that call user-defined functions or that have placeholders in
+    /// a function's arguments, which are re-parsed for each set.
+    fn executemany<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+        operation: &str,
+        seq_of_parameters: Vec<Vec<&PyAny>>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        slf._ensure_open(py)?;
+
+        let statement = PreparedStatement::from(operation);
+        let parsed: Option<Statement> = iif!(
+            slf.connection.borrow(py)._calls_functions(operation),
+            None,
+            statement.parse()
+        );
+        let encoding: Encoding = slf.connection.borrow(py).encoding.clone();
+        let mut total: isize = 0;
+
+        for parameters in seq_of_parameters {
+            let bound: Option<Statement> = match &parsed {
+                Some(parsed) => statement.bind_parsed(parsed, &parameters, &encoding)?,
+                None => None,
+            };
+
+            match bound {
+                Some(bound) => slf._execute_parsed(py, bound)?,
+                None => slf._execute(py, &statement.bind(&parameters, &encoding)?)?,
+            }
+
+            if slf.description.is_some() {
+                slf._reset();
+                return Err(ProgrammingError::new_err(
+                    "executemany() can only execute DML statements.",
+                ));
+            }
+
+            total += max(slf.rowcount.unwrap_or(0), 0);
+        }
+
+        slf.rowcount = Some(total);
+
+        Ok(slf)
+    }
+
+    #[pyo3(text_signature = "($self) -> Optional[Sequence[Any]]")]
+    /// Fetch the next row of a query result set, returning a single
+    /// sequence, or None when no more data is available.
+    fn fetchone(slf: PyRefMut<Self>, py: Python) -> PyResult<Option<PyObject>> {
+        Ok(Cursor::_fetch(slf, py, Some(1))?.pop())
+    }
+
+    #[pyo3(text_signature = "($self, size: Optional[int] = None) -> Sequence[Sequence[Any]]")]
+    /// Fetch the next set of rows of a query result, returning a
+    /// sequence of sequences. The number of rows to fetch per call
+    /// is specified by `size`, falling back to the cursor's
+    /// `arraysize` if it's not given. Fewer rows may be returned
+    /// if fewer than `size` rows remain.
+    fn fetchmany(slf: PyRefMut<Self>, py: Python, size: Option<usize>) -> PyResult<Vec<PyObject>> {
+        let size: usize = size.or(slf.arraysize).unwrap_or(1);
+
+        Cursor::_fetch(slf, py, Some(size))
+    }
+
+    #[pyo3(text_signature = "($self) -> Sequence[Sequence[Any]]")]
+    /// Fetch all (remaining) rows of a query result, returning
+    /// them as a sequence of sequences.
+    fn fetchall(slf: PyRefMut<Self>, py: Python) -> PyResult<Vec<PyObject>> {
+        Cursor::_fetch(slf, py, None)
+    }
+
+    #[pyo3(text_signature = "($self) -> Optional[bool]")]
+    /// Skip to the next available result set. DataFlex operations
+    /// only ever produce a single result set, so this always
+    /// returns None.
+    fn nextset(&mut self, py: Python) -> PyResult<Option<bool>> {
+        self._ensure_result_set(py)?;
+
+        Ok(None)
+    }
+
+    #[pyo3(text_signature = "($self, sizes: Sequence[Any]) -> None")]
+    /// Predefine memory areas for the operation's parameters.
+    /// This is a no-op, as permitted by the DB API.
+    fn setinputsizes(&self, sizes: &PyAny) -> PyResult<()> {
+        Ok(())
+    }
+
+    #[pyo3(text_signature = "($self, size: int, column: Optional[int] = None) -> None")]
+    /// Set a column buffer size for fetches of large columns.
+    /// This is a no-op, as permitted by the DB API.
+    fn setoutputsize(&self, size: usize, column: Option<usize>) -> PyResult<()> {
+        Ok(())
+    }
+
     // </editor-fold desc="// Required methods ...">
 
     // # <editor-fold desc="// 'Optional' methods ...">
@@ -744,40 +1357,473 @@ impl Cursor {
 // <editor-fold desc="// Type Objects ...">
 
 // Required Type Objects
-// STRING type
-// BINARY type
-// NUMBER type
-// DATETIME type
-// ROWID type
 // SQL NULL values are represented by the Python None singleton on input and output.
 
+/// Type code describing string-based columns (i.e. ASCII and TEXT)
+pub const STRING: usize = 1;
+/// Type code describing binary columns
+pub const BINARY: usize = 2;
+/// Type code describing numeric columns
+pub const NUMBER: usize = 3;
+/// Type code describing date and time columns
+pub const DATETIME: usize = 4;
+/// Type code describing record numbers
+pub const ROWID: usize = 5;
+
 // </editor-fold desc="// Type Objects ...">
 
 // </editor-fold desc="// Objects ...">
 
+// <editor-fold desc="// Conversions ...">
+
+/// Convert a GlueSQL `Value` into its native Python equivalent
+pub(crate) fn py_from_value(py: Python, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(val) => val.to_object(py),
+        Value::I8(val) => val.to_object(py),
+        Value::I64(val) => val.to_object(py),
+        Value::F64(val) => val.to_object(py),
+        Value::Str(val) => val.to_object(py),
+        Value::Decimal(val) => py
+            .import("decimal")?
+            .getattr("Decimal")?
+            .call1((val.to_string(),))?
+            .to_object(py),
+        Value::Date(val) => PyDate::from(*val).into_py(py),
+        Value::Time(val) => PyTime::from(*val).into_py(py),
+        Value::Timestamp(val) => PyDateTime::from(*val).into_py(py),
+        Value::List(values) => values
+            .iter()
+            .map(|val| py_from_value(py, val))
+            .collect::<PyResult<Vec<PyObject>>>()?
+            .to_object(py),
+        Value::Map(values) => {
+            let dict = PyDict::new(py);
+
+            for (key, val) in values {
+                dict.set_item(key, py_from_value(py, val)?)?;
+            }
+
+            dict.to_object(py)
+        }
+        other => format!("{:?}", other).to_object(py),
+    })
+}
+
+/// Convert a native Python value into its GlueSQL equivalent
+pub(crate) fn value_from_py(value: &PyAny, encoding: &Encoding) -> PyResult<Value> {
+    if value.is_none() {
+        return Ok(Value::Null);
+    }
+
+    // `bool` is a subclass of `int`, so it has to be checked first
+    if value.is_instance::<PyBool>()? {
+        return Ok(Value::Bool(value.extract()?));
+    }
+
+    if value.is_instance::<PyLong>()? {
+        return value
+            .extract::<i64>()
+            .map(Value::I64)
+            .map_err(|_| DataError::new_err("Python int too large to convert"));
+    }
+
+    if value.is_instance::<PyFloat>()? {
+        return Ok(Value::F64(value.extract()?));
+    }
+
+    let py = value.py();
+
+    if py
+        .import("builtins")?
+        .getattr("isinstance")?
+        .call1((value, py.import("decimal")?.getattr("Decimal")?))?
+        .extract::<bool>()?
+    {
+        if !value.call_method0("is_finite")?.extract::<bool>()? {
+            return Err(DataError::new_err(format!(
+                "non-finite decimal '{}' can't be stored",
+                value.str()?
+            )));
+        }
+
+        // Decimals are formatted in fixed-point notation
+        // because `rust_decimal` can't parse exponents
+        return match value
+            .call_method1("__format__", ("f",))?
+            .extract::<&str>()?
+            .parse::<Decimal>()
+        {
+            Ok(number) => Ok(Value::Decimal(number)),
+            Err(_) => Err(DataError::new_err(format!(
+                "decimal '{}' is out of range",
+                value.str()?
+            ))),
+        };
+    }
+
+    if value.is_instance::<PyString>()? {
+        return Ok(Value::Str(value.extract()?));
+    }
+
+    // `datetime` is a subclass of `date`, so it has to be checked first
+    if let Ok(timestamp) = value.extract::<PyDateTime>() {
+        return Ok(Value::Timestamp(timestamp.0));
+    }
+
+    if let Ok(date) = value.extract::<PyDate>() {
+        return Ok(Value::Date(date.0));
+    }
+
+    if let Ok(time) = value.extract::<PyTime>() {
+        return Ok(Value::Time(time.0));
+    }
+
+    // Bytes are decoded the same way as the database's ASCII columns
+    if value.is_instance::<PyBytes>()? {
+        return Ok(Value::Str(
+            value
+                .extract::<&[u8]>()?
+                .iter()
+                .map(|byte| encoding.decode_byte(*byte))
+                .collect(),
+        ));
+    }
+
+    Err(ProgrammingError::new_err(format!(
+        "type '{}' is not supported",
+        value.get_type().name()?
+    )))
+}
+
+/// Convert a GlueSQL `Row` into a Python tuple of native values
+pub(crate) fn py_from_row(py: Python, row: &Row) -> PyResult<PyObject> {
+    Ok(PyTuple::new(
+        py,
+        row.0
+            .iter()
+            .map(|value| py_from_value(py, value))
+            .collect::<PyResult<Vec<PyObject>>>()?,
+    )
+    .to_object(py))
+}
+
+// </editor-fold desc="// Conversions ...">
+
 // <editor-fold desc="// Tests ...">
 
 #[cfg(test)]
 mod tests {
     #![allow(unused_imports)]
-    use super::{Connection, Cursor, CursorDescription};
+    use gluesql::prelude::Value;
+    use pyo3::prelude::*;
+
+    use super::{
+        connect, value_from_py, Connection, Cursor, CursorDescription, PreparedStatement, DATETIME,
+        NUMBER, STRING,
+    };
+    use crate::enums::{DataType, Encoding};
+    use crate::exceptions::{
+        py_err_from_sql, DataError, NotSupportedError, OperationalError, ProgrammingError,
+    };
+    use crate::structs::Column;
+
+    /// The directory holding the `dev_data` test table
+    const DEV_DATA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/dev_data");
 
     #[test]
     /// Test that the `Connection` structure behaves as expected
     fn connects() {
-        todo!()
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let connection =
+                Py::new(py, connect(DEV_DATA.to_string(), None, None, None).unwrap()).unwrap();
+
+            assert!(connection.borrow(py).diagnostics.is_empty());
+            assert!(connection
+                .borrow(py)
+                .db_path()
+                .unwrap()
+                .ends_with("dev_data"));
+
+            // Interrupting the connection stops the scan that's running
+            {
+                let connection = connection.borrow(py);
+
+                connection.interrupt().unwrap();
+
+                let engine = connection._engine();
+                let db = engine
+                    .as_ref()
+                    .and_then(|engine| engine.storage.as_ref())
+                    .unwrap();
+                let mut rows = db["dev_data"].clone().iter();
+
+                assert!(rows.next().unwrap().is_ok());
+
+                let mut rows = rows.with_interrupt(db.interrupt_flag());
+                let error = py_err_from_sql(rows.next().unwrap().unwrap_err());
+
+                assert!(error.is_instance::<OperationalError>(py));
+                assert_eq!(error.pvalue(py).to_string(), "interrupted");
+                assert!(rows.next().is_none());
+            }
+
+            // The next statement isn't affected by the interrupt
+            let cursor = Connection::execute(
+                connection.borrow(py),
+                py,
+                "SELECT int_col FROM dev_data",
+                None,
+            )
+            .unwrap();
+
+            assert_eq!(cursor.borrow(py).rowcount, Some(10));
+
+            // Closing the connection more than once is harmless
+            connection.borrow(py).close(py).unwrap();
+            connection.borrow(py).close(py).unwrap();
+
+            assert!(connection.borrow(py).closed());
+
+            for error in [
+                Connection::cursor(connection.borrow(py)).unwrap_err(),
+                Connection::execute(connection.borrow(py), py, "SELECT 1", None).unwrap_err(),
+                Cursor::fetchall(cursor.borrow_mut(py), py).unwrap_err(),
+                connection.borrow(py).interrupt().unwrap_err(),
+            ] {
+                assert!(error.is_instance::<ProgrammingError>(py));
+            }
+        });
+    }
+
+    #[test]
+    /// Test that connections commit and roll back like `sqlite3`'s
+    fn manages_transactions() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let connection =
+                Py::new(py, connect(DEV_DATA.to_string(), None, None, None).unwrap()).unwrap();
+
+            assert!(connection.borrow(py).commit().is_ok());
+            assert!(connection
+                .borrow(py)
+                .rollback()
+                .unwrap_err()
+                .is_instance::<NotSupportedError>(py));
+
+            // Leaving the block commits, and the rollback that an
+            // exception triggers doesn't replace the exception
+            pyo3::py_run!(
+                py,
+                connection,
+                r#"
+with connection:
+    connection.execute("SELECT int_col FROM dev_data")
+
+try:
+    with connection:
+        raise KeyError("boom")
+except KeyError:
+    pass
+else:
+    raise AssertionError("the KeyError was swallowed")
+
+assert not connection.closed
+"#
+            );
+
+            let read_only =
+                connect(format!("file:{}?mode=ro", DEV_DATA), Some(true), None, None).unwrap();
+
+            assert!(read_only.commit().is_ok());
+            assert!(read_only.rollback().is_ok());
+        });
     }
 
     #[test]
     /// Test that the `Cursor` structure behaves as expected
     fn creates_cursors() {
-        todo!()
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let connection =
+                Py::new(py, connect(DEV_DATA.to_string(), None, None, None).unwrap()).unwrap();
+            let cursor = Py::new(py, Connection::cursor(connection.borrow(py)).unwrap()).unwrap();
+
+            pyo3::py_run!(
+                py,
+                cursor,
+                r#"
+import datetime
+
+assert cursor.rowcount == -1
+assert cursor.description is None
+
+cursor.execute("SELECT int_col, date_col, ascii_col FROM dev_data WHERE int_col > ?", [1000])
+
+assert cursor.rowcount == 6
+assert [tuple(column) for column in cursor.description] == [
+    ("int_col", 3, 15, 7, 14, 0, False),
+    ("date_col", 4, 10, 3, None, None, True),
+    ("ascii_col", 1, 200, 200, None, None, False),
+]
+
+assert cursor.fetchone() == (5823, datetime.date(2010, 1, 31), "Executive news bit.")
+assert [row[0] for row in cursor.fetchmany()] == [2304]
+
+cursor.arraysize = 2
+
+assert [row[0] for row in cursor.fetchmany()] == [6429, 1980]
+assert [row[0] for row in cursor.fetchmany(5)] == [6079, 1257]
+assert cursor.fetchmany() == []
+assert cursor.fetchone() is None
+
+cursor.execute("SELECT int_col FROM dev_data")
+
+assert cursor.rowcount == 10
+assert [row[0] for row in cursor.fetchall()] == [
+    5823, 236, 2304, 6429, 1980, 6079, 330, 419, 899, 1257
+]
+assert cursor.fetchall() == []
+
+cursor.close()
+
+assert cursor.description is None
+"#
+            );
+
+            assert!(Cursor::fetchone(cursor.borrow_mut(py), py)
+                .unwrap_err()
+                .is_instance::<ProgrammingError>(py));
+        });
     }
 
     #[test]
     /// Test that the `CursorDescription` structure behaves as expected
     fn cursors_are_descriptive() {
-        todo!()
+        let ascii = CursorDescription::from_column(
+            "NAME",
+            &Column {
+                name: "NAME".to_string(),
+                length: 30,
+                data_type: DataType::Ascii,
+                ..Column::default()
+            },
+        );
+
+        assert_eq!(ascii.name, "NAME");
+        assert_eq!(ascii.type_code, STRING);
+        assert_eq!(ascii.display_size, Some(30));
+        assert_eq!(ascii.internal_size, Some(30));
+        assert_eq!(ascii.precision, None);
+
+        let numeric = CursorDescription::from_column(
+            "TOTAL",
+            &Column {
+                name: "TOTAL".to_string(),
+                length: 6,
+                decimal_points: 2,
+                data_type: DataType::Float,
+                ..Column::default()
+            },
+        );
+
+        assert_eq!(numeric.type_code, NUMBER);
+        assert_eq!(numeric.precision, Some(12));
+        assert_eq!(numeric.scale, Some(2));
+        assert_eq!(numeric.display_size, Some(14));
+
+        let date = CursorDescription::from_column(
+            "CREATED",
+            &Column {
+                length: 3,
+                data_type: DataType::Date,
+                ..Column::default()
+            },
+        );
+
+        assert_eq!(date.type_code, DATETIME);
+        assert_eq!(date.null_ok, Some(true));
+    }
+
+    #[test]
+    /// Test that parameters and function results share one conversion
+    fn converts_values() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let decimal = py.import("decimal").unwrap().getattr("Decimal").unwrap();
+
+            for (text, expected) in [("1.50", "1.50"), ("1E+2", "100"), ("-0.001", "-0.001")] {
+                let value =
+                    value_from_py(decimal.call1((text,)).unwrap(), &Encoding::Latin1).unwrap();
+
+                assert_eq!(value, Value::Decimal(expected.parse().unwrap()));
+            }
+
+            assert!(
+                value_from_py(decimal.call1(("NaN",)).unwrap(), &Encoding::Latin1)
+                    .unwrap_err()
+                    .is_instance::<DataError>(py)
+            );
+
+            // Bytes are decoded with the database's encoding
+            let text = pyo3::types::PyBytes::new(py, b"caf\x82");
+
+            assert_eq!(
+                value_from_py(text, &Encoding::Cp850).unwrap(),
+                Value::Str("café".to_string())
+            );
+            assert_eq!(
+                value_from_py(text, &Encoding::Latin1).unwrap(),
+                Value::Str("caf\u{82}".to_string())
+            );
+            assert_eq!(
+                PreparedStatement::from("SELECT ?")
+                    .bind(&[text], &Encoding::Cp850)
+                    .unwrap(),
+                "SELECT 'café'"
+            );
+
+            let connection =
+                Py::new(py, connect(DEV_DATA.to_string(), None, None, None).unwrap()).unwrap();
+
+            // Errors name the offending parameter or function
+            pyo3::py_run!(
+                py,
+                connection,
+                r#"
+import decimal
+
+rows = connection.execute(
+    "SELECT int_col FROM dev_data WHERE int_col < ?", [decimal.Decimal("3E+2")]
+).fetchall()
+assert rows == [(236,)], rows
+
+for parameters, error_type in (([object()], "ProgrammingError"), ([2**64], "DataError")):
+    try:
+        connection.execute("SELECT int_col FROM dev_data WHERE int_col = ?", parameters)
+    except Exception as error:
+        assert type(error).__name__ == error_type, repr(error)
+        assert "parameter 1" in str(error), str(error)
+    else:
+        raise AssertionError("binding should fail")
+
+connection.create_function("broken", 0, lambda: object())
+
+try:
+    connection.execute("SELECT broken() FROM dev_data")
+except Exception as error:
+    assert "broken()" in str(error), str(error)
+else:
+    raise AssertionError("calling broken() should fail")
+"#
+            );
+        });
     }
 }
 
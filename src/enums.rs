@@ -333,13 +333,283 @@ where
 
 // </editor-fold desc="// Version ...">
 
+// <editor-fold desc="// Encoding ...">
+
+/// The characters encoded by bytes `0x80` through `0xFF` in code page 437
+/// (the original IBM PC / US MS-DOS code page)
+#[rustfmt::skip]
+const CP437_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ',
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»',
+    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
+    '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
+    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
+    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
+    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{A0}',
+];
+
+/// The characters encoded by bytes `0x80` through `0xFF` in code page 850
+/// (the Western European MS-DOS code page)
+#[rustfmt::skip]
+const CP850_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', 'ƒ',
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '®', '¬', '½', '¼', '¡', '«', '»',
+    '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À', '©', '╣', '║', '╗', '╝', '¢', '¥', '┐',
+    '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã', '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤',
+    'ð', 'Ð', 'Ê', 'Ë', 'È', 'ı', 'Í', 'Î', 'Ï', '┘', '┌', '█', '▄', '¦', 'Ì', '▀',
+    'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', 'þ', 'Þ', 'Ú', 'Û', 'Ù', 'ý', 'Ý', '¯', '´',
+    '\u{AD}', '±', '‗', '¾', '¶', '§', '÷', '¸', '°', '¨', '·', '¹', '³', '²', '■', '\u{A0}',
+];
+
+/// The characters encoded by bytes `0x80` through `0x9F` in Windows-1252
+/// (the bytes it leaves undefined are passed through as C1 control characters
+/// so that they survive a round trip, and `0xA0` onward matches Latin-1)
+#[rustfmt::skip]
+const WINDOWS_1252_C1: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+/// The character encoding used for the text stored in a DataFlex table
+pub enum Encoding {
+    #[default]
+    Latin1,
+    Cp437,
+    Cp850,
+    Windows1252,
+    /// Every byte of a field is kept (with no trimming or
+    /// filtering) and mapped to the character with the same
+    /// code point, so values can be turned back into the
+    /// exact bytes they were read from
+    Raw,
+}
+
+unsafe impl Send for Encoding {}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Encoding::Latin1 => "LATIN-1",
+                Encoding::Cp437 => "CP437",
+                Encoding::Cp850 => "CP850",
+                Encoding::Windows1252 => "WINDOWS-1252",
+                Encoding::Raw => "RAW",
+            }
+        )
+    }
+}
+
+impl Encoding {
+    /// Get the encoding referred to by `name` (ignoring case and
+    /// allowing the common aliases for each code page)
+    pub fn from_name<T: AsRef<str>>(name: T) -> Option<Encoding> {
+        match name.as_ref().to_lowercase().replace('_', "-").as_str() {
+            "latin-1" | "latin1" | "iso-8859-1" => Some(Encoding::Latin1),
+            "cp437" | "ibm437" | "437" => Some(Encoding::Cp437),
+            "cp850" | "ibm850" | "850" => Some(Encoding::Cp850),
+            "cp1252" | "windows-1252" | "1252" => Some(Encoding::Windows1252),
+            "raw" | "bytes" => Some(Encoding::Raw),
+            _ => None,
+        }
+    }
+
+    /// Decode a single byte
+    pub fn decode_byte(&self, byte: u8) -> char {
+        match self {
+            Encoding::Cp437 if byte > 0x7F => CP437_HIGH[(byte - 0x80) as usize],
+            Encoding::Cp850 if byte > 0x7F => CP850_HIGH[(byte - 0x80) as usize],
+            Encoding::Windows1252 if (0x80..0xA0).contains(&byte) => {
+                WINDOWS_1252_C1[(byte - 0x80) as usize]
+            }
+            _ => char::from(byte),
+        }
+    }
+
+    /// Encode a single character, returning `None` if
+    /// the encoding has no byte for it
+    pub fn encode_char(&self, chr: char) -> Option<u8> {
+        let table: &[char] = match self {
+            _ if chr.is_ascii() => return Some(chr as u8),
+            Encoding::Latin1 | Encoding::Raw => return u8::try_from(chr as u32).ok(),
+            Encoding::Cp437 => &CP437_HIGH,
+            Encoding::Cp850 => &CP850_HIGH,
+            Encoding::Windows1252 => match u8::try_from(chr as u32) {
+                Ok(byte) if byte >= 0xA0 => return Some(byte),
+                _ => &WINDOWS_1252_C1,
+            },
+        };
+
+        table
+            .iter()
+            .position(|entry| *entry == chr)
+            .map(|position| 0x80 + position as u8)
+    }
+}
+
+// </editor-fold desc="// Encoding ...">
+
+// <editor-fold desc="// DeletedRecords ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+/// How deleted (i.e. zero-filled) records are
+/// treated when iterating over a table
+pub enum DeletedRecords {
+    #[default]
+    Skip,
+    Include,
+}
+
+unsafe impl Send for DeletedRecords {}
+
+impl fmt::Display for DeletedRecords {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DeletedRecords::Skip => "SKIP",
+                DeletedRecords::Include => "INCLUDE",
+            }
+        )
+    }
+}
+
+// </editor-fold desc="// DeletedRecords ...">
+
+// <editor-fold desc="// Whitespace ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+/// How the whitespace and control bytes in
+/// ASCII and TEXT fields are treated
+pub enum Whitespace {
+    /// Drop control bytes (other than tabs and line breaks)
+    /// and trim whitespace from both ends of each value
+    #[default]
+    Trim,
+    /// Keep every byte except the trailing NUL / space padding,
+    /// which (like DataFlex's own FIND comparisons) is ignored
+    Preserve,
+}
+
+unsafe impl Send for Whitespace {}
+
+impl fmt::Display for Whitespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Whitespace::Trim => "TRIM",
+                Whitespace::Preserve => "PRESERVE",
+            }
+        )
+    }
+}
+
+// </editor-fold desc="// Whitespace ...">
+
+// <editor-fold desc="// DiagnosticKind ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+/// The kind of structure a load-time diagnostic refers to
+pub enum DiagnosticKind {
+    #[default]
+    Table,
+    FileListSlot,
+    Index,
+    Column,
+}
+
+unsafe impl Send for DiagnosticKind {}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DiagnosticKind::Table => "TABLE",
+                DiagnosticKind::FileListSlot => "FILELIST SLOT",
+                DiagnosticKind::Index => "INDEX",
+                DiagnosticKind::Column => "COLUMN",
+            }
+        )
+    }
+}
+
+// </editor-fold desc="// DiagnosticKind ...">
+
+// <editor-fold desc="// DefinitionFormat ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+/// The kind of file a table definition was read from
+pub enum DefinitionFormat {
+    /// A `.FD` file of `#REPLACE` macros (names and coarse types only)
+    Fd,
+    /// A `.DEF` file (the full printed definition of a table)
+    #[default]
+    Def,
+}
+
+unsafe impl Send for DefinitionFormat {}
+
+impl fmt::Display for DefinitionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DefinitionFormat::Fd => "FD",
+                DefinitionFormat::Def => "DEF",
+            }
+        )
+    }
+}
+
+// </editor-fold desc="// DefinitionFormat ...">
+
+// <editor-fold desc="// RowFormat ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+/// The Python type records are returned as when
+/// a table is accessed directly (i.e. without SQL)
+pub enum RowFormat {
+    #[default]
+    NamedTuple,
+    Dict,
+}
+
+unsafe impl Send for RowFormat {}
+
+impl fmt::Display for RowFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                RowFormat::NamedTuple => "namedtuple",
+                RowFormat::Dict => "dict",
+            }
+        )
+    }
+}
+
+// </editor-fold desc="// RowFormat ...">
+
 // <editor-fold desc="// Tests ...">
 
 #[cfg(test)]
 mod tests {
     #![allow(unused_imports)]
     use super::{
-        CompressionType, DataType, IndexCollation, IndexType, LockType, TransactionType, Version,
+        CompressionType, DataType, DeletedRecords, Encoding, IndexCollation, IndexType, LockType,
+        RowFormat, TransactionType, Version,
     };
 
     #[test]
@@ -1,9 +1,13 @@
 // Python-compatible error classes.
 
 // Third-Party Imports
+use gluesql::core::result::Error as SqlError;
 use pyo3::create_exception;
 use pyo3::prelude::*;
 
+// Crate-Level Imports
+use crate::sql::StorageError;
+
 // <editor-fold desc="// Component Registration ...">
 
 /// Register the Rust code to be "exported" to Python
@@ -111,3 +115,46 @@ create_exception!(ferroflex, NotSupportedError, DatabaseError);
 // </editor-fold desc="// DB API Required Exceptions ...">
 
 // </editor-fold desc="// Exceptions ...">
+
+// <editor-fold desc="// Conversions ...">
+
+/// Map an error raised by GlueSQL onto the matching
+/// exception from the DB API's exception hierarchy
+pub(crate) fn py_err_from_sql(error: SqlError) -> PyErr {
+    let message: String = error.to_string();
+
+    match error {
+        // Malformed SQL, unknown tables / columns, etc.
+        SqlError::Parser(_)
+        | SqlError::Translate(_)
+        | SqlError::Fetch(_)
+        | SqlError::Table(_)
+        | SqlError::Execute(_)
+        | SqlError::Select(_)
+        | SqlError::Alter(_)
+        | SqlError::Aggregate(_)
+        | SqlError::Update(_) => ProgrammingError::new_err(message),
+        // Values that can't be evaluated, converted, or stored
+        SqlError::Value(_)
+        | SqlError::Evaluate(_)
+        | SqlError::Row(_)
+        | SqlError::Literal(_)
+        | SqlError::Interval(_)
+        | SqlError::StringExt(_) => DataError::new_err(message),
+        // Uniqueness and other constraint violations
+        SqlError::Validate(_) => IntegrityError::new_err(message),
+        // Failures reported by ferroflex's storage layer itself
+        SqlError::Storage(_) | SqlError::StorageMsg(_) => {
+            match StorageError::from_sql_error(&error) {
+                Some(StorageError::Interrupted) => OperationalError::new_err("interrupted"),
+                Some(StorageError::ReadOnly) | Some(StorageError::Unsupported(_)) => {
+                    NotSupportedError::new_err(message)
+                }
+                _ => OperationalError::new_err(message),
+            }
+        }
+        _ => DatabaseError::new_err(message),
+    }
+}
+
+// </editor-fold desc="// Conversions ...">
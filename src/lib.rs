@@ -1,7 +1,6 @@
 #![feature(
     generic_associated_types,
     associated_type_bounds,
-    in_band_lifetimes,
     never_type
 )]
 #![allow(dead_code, unused_doc_comments)]
@@ -14,10 +13,16 @@ extern crate core;
 pub mod dbapi;
 pub mod enums;
 pub mod exceptions;
+pub mod options;
 pub mod sql;
 pub mod structs;
 pub mod utils;
 
+// Standard Library Imports
+use std::cmp::Ordering as Comparison;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
 // Third-Party Imports
 use pyo3::prelude::*;
 use pyo3::types::PySlice;
@@ -49,10 +54,8 @@ pub fn ferroflex(py: Python, module: &PyModule) -> PyResult<()> {
     dbapi::register_components(py, module)?;
     exceptions::register_components(py, module)?;
     utils::register_components(py, module)?;
+    structs::register_components(py, module)?;
 
-    // // Call the `register` function from the other crate-level modules
-    // structs::register_components(py, module)?;
-    //
     // // Call the `register` function from the `sql` sub-module
     // sql::register_components(py, module)?;
 
@@ -101,4 +104,51 @@ where
     }
 }
 
+#[derive(Clone, Debug, Default)]
+/// A thread-safe flag used to cancel long-running
+/// table scans from outside the scanning thread
+pub struct InterruptFlag(Arc<AtomicBool>);
+
+unsafe impl Send for InterruptFlag {}
+
+// Every clone of a given flag shares the same underlying
+// state, so flags never affect the equality or ordering
+// of the structs that carry them
+impl PartialEq for InterruptFlag {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for InterruptFlag {}
+
+impl PartialOrd for InterruptFlag {
+    fn partial_cmp(&self, other: &Self) -> Option<Comparison> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InterruptFlag {
+    fn cmp(&self, _other: &Self) -> Comparison {
+        Comparison::Equal
+    }
+}
+
+impl InterruptFlag {
+    /// Request that any scan observing the flag stop
+    pub fn set(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Reset the flag so that new scans can proceed
+    pub fn clear(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+
+    /// Check whether or not an interrupt has been requested
+    pub fn is_set(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
 // </editor-fold desc="// Custom Types ...">
@@ -0,0 +1,341 @@
+// Options controlling how a DataFlex "database" is opened and read
+
+// Standard Library Imports
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+// Third-Party Imports
+use pyo3::PyResult;
+use serde::{Deserialize, Serialize};
+
+// Crate-Level Imports
+use crate::enums::{DeletedRecords, Encoding, Version, Whitespace};
+use crate::exceptions::{NotSupportedError, ProgrammingError};
+
+// <editor-fold desc="// OpenOptions ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+/// The options used when opening a DataFlex "database"
+/// and each of the tables it contains
+pub struct OpenOptions {
+    /// Indicates that the database must never be written to
+    pub read_only: bool,
+    /// The encoding of the text stored in the database's tables
+    /// (and of the names and descriptions in its filelist)
+    pub encoding: Encoding,
+    /// Per-table overrides of `encoding`, keyed by
+    /// each table's (uppercase) root name
+    pub table_encodings: BTreeMap<String, Encoding>,
+    /// How the whitespace and control bytes in
+    /// ASCII and TEXT fields are treated
+    pub whitespace: Whitespace,
+    /// Additional directories to search for tables' `.tag` files
+    /// (relative directories are resolved against each table's directory)
+    pub tag_paths: Vec<PathBuf>,
+    /// How many of each table's ancestor directories to
+    /// search for its `.tag` file
+    pub tag_depth: usize,
+    /// How deleted records are treated when reading tables
+    pub deleted_records: DeletedRecords,
+    /// Indicates that structures which can't be parsed should
+    /// raise an error instead of being skipped and recorded
+    /// as diagnostics
+    pub strict: bool,
+    /// The DataFlex version whose layout the database's `filelist.cfg`
+    /// entries use. `Unknown` (i.e. `filelist=auto`) detects the version
+    /// from the db's tables, reading the filelist with that version's
+    /// layout (or the 3.0 layout if the tables don't agree).
+    pub filelist_version: Version,
+}
+
+unsafe impl Send for OpenOptions {}
+
+impl OpenOptions {
+    // <editor-fold desc="// 'Private' Methods ...">
+
+    /// Apply a single `key=value` pair from a URI's query string
+    fn _apply(&mut self, key: &str, value: &str) -> PyResult<()> {
+        let invalid = || -> PyResult<()> {
+            Err(ProgrammingError::new_err(format!(
+                "invalid value for URI option '{}': '{}'",
+                key, value
+            )))
+        };
+
+        match key.to_lowercase().as_str() {
+            "mode" => match value.to_lowercase().as_str() {
+                "ro" => self.read_only = true,
+                "rw" => self.read_only = false,
+                _ => return invalid(),
+            },
+            "encoding" => match Encoding::from_name(value) {
+                Some(encoding) => self.encoding = encoding,
+                None => return invalid(),
+            },
+            name if name.starts_with("encoding.") && name.len() > 9 => {
+                match Encoding::from_name(value) {
+                    Some(encoding) => {
+                        self.table_encodings
+                            .insert(name[9..].to_uppercase(), encoding);
+                    }
+                    None => return invalid(),
+                }
+            }
+            "whitespace" => match value.to_lowercase().as_str() {
+                "trim" => self.whitespace = Whitespace::Trim,
+                "preserve" => self.whitespace = Whitespace::Preserve,
+                _ => return invalid(),
+            },
+            "tags" => {
+                if value.is_empty() {
+                    return invalid();
+                }
+
+                self.tag_paths.push(PathBuf::from(value));
+            }
+            "tag_depth" => match value.parse::<usize>() {
+                Ok(depth) => self.tag_depth = depth,
+                Err(_) => return invalid(),
+            },
+            "lock" => match value.to_lowercase().as_str() {
+                "none" => {}
+                "shared" | "exclusive" => {
+                    return Err(NotSupportedError::new_err(format!(
+                        "{} file locking is not supported",
+                        value.to_lowercase()
+                    )))
+                }
+                _ => return invalid(),
+            },
+            "deleted" => match value.to_lowercase().as_str() {
+                "skip" => self.deleted_records = DeletedRecords::Skip,
+                "include" => self.deleted_records = DeletedRecords::Include,
+                _ => return invalid(),
+            },
+            "filelist" => match value.to_lowercase().as_str() {
+                "auto" => self.filelist_version = Version::Unknown,
+                "2.3" | "2.3b" => self.filelist_version = Version::V23B,
+                "3" | "3.0" => self.filelist_version = Version::V30,
+                _ => return invalid(),
+            },
+            "strict" => match value.to_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => self.strict = true,
+                "0" | "false" | "no" | "off" => self.strict = false,
+                _ => return invalid(),
+            },
+            _ => {
+                return Err(ProgrammingError::new_err(format!(
+                    "unrecognized URI option: '{}'",
+                    key
+                )))
+            }
+        }
+
+        Ok(())
+    }
+
+    // </editor-fold desc="// 'Private' Methods ...">
+
+    // <editor-fold desc="// Public Methods ...">
+
+    /// Parse a `file:` URI (e.g. `file:/path/to/db?mode=ro&encoding=latin-1`)
+    /// into the path it refers to and the options it specifies.
+    ///
+    /// Supported options:
+    ///   - `mode`: `ro` (read-only) or `rw` (read-write, the default)
+    ///   - `encoding`: the encoding of the database's text: `latin-1` (the
+    ///     default), `cp437`, `cp850`, `windows-1252`, or `raw`
+    ///   - `encoding.<table>`: the encoding of a single table's text
+    ///     (overriding `encoding` for the table with that root name)
+    ///   - `whitespace`: `trim` (the default) or `preserve` the leading
+    ///     whitespace and control bytes in text, only stripping the
+    ///     trailing NUL / space padding
+    ///   - `tags`: a directory to search for `.tag` files (may be repeated,
+    ///     relative directories are resolved against each table's directory)
+    ///   - `tag_depth`: how many of each table's ancestor directories to
+    ///     search for its `.tag` file (`0` by default)
+    ///   - `lock`: `none` (the default); `shared` and `exclusive` raise a
+    ///     `NotSupportedError` until file locking is implemented
+    ///   - `deleted`: `skip` (the default) or `include` deleted records
+    ///   - `strict`: raise an error for unparseable structures instead of
+    ///     skipping them (`0` by default)
+    ///   - `filelist`: the layout of the `filelist.cfg` file's entries:
+    ///     `auto` (the default), `2.3b`, or `3.0`
+    pub fn from_uri<T: AsRef<str>>(uri: T) -> PyResult<(String, OpenOptions)> {
+        let uri: &str = uri.as_ref();
+
+        let location: &str = match uri.get(..5) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("file:") => &uri[5..],
+            _ => {
+                return Err(ProgrammingError::new_err(format!(
+                    "expected a 'file:' URI but got '{}'",
+                    uri
+                )))
+            }
+        };
+
+        let (path, query) = match location.split_once('?') {
+            Some((path, query)) => (path, query),
+            None => (location, ""),
+        };
+
+        // Drop any (empty or `localhost`) authority
+        let path: &str = match path.strip_prefix("//") {
+            Some(rest) => match rest.find('/') {
+                Some(idx)
+                    if rest[..idx].is_empty() || rest[..idx].eq_ignore_ascii_case("localhost") =>
+                {
+                    &rest[idx..]
+                }
+                _ => {
+                    return Err(ProgrammingError::new_err(format!(
+                        "invalid URI authority in '{}'",
+                        uri
+                    )))
+                }
+            },
+            None => path,
+        };
+
+        let mut path: String = percent_decode(path)?;
+
+        // `file:///C:/...` style URIs for Windows drive paths
+        if path.len() > 2 && path.starts_with('/') && path.as_bytes()[2] == b':' {
+            path.remove(0);
+        }
+
+        if path.is_empty() {
+            return Err(ProgrammingError::new_err(format!(
+                "URI '{}' doesn't include a path",
+                uri
+            )));
+        }
+
+        let mut options: OpenOptions = OpenOptions::default();
+
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+
+            options._apply(&percent_decode(key)?, &percent_decode(value)?)?;
+        }
+
+        Ok((path, options))
+    }
+
+    /// The encoding of the text stored in the table at `table_path`
+    pub fn encoding_for<P: AsRef<Path>>(&self, table_path: P) -> Encoding {
+        table_path
+            .as_ref()
+            .file_stem()
+            .and_then(|stem| {
+                self.table_encodings
+                    .get(&stem.to_string_lossy().to_uppercase())
+            })
+            .unwrap_or(&self.encoding)
+            .clone()
+    }
+
+    // </editor-fold desc="// Public Methods ...">
+}
+
+// </editor-fold desc="// OpenOptions ...">
+
+// <editor-fold desc="// Helpers ...">
+
+/// Decode the `%XX` escapes in a URI component
+fn percent_decode(value: &str) -> PyResult<String> {
+    let mut decoded: Vec<u8> = Vec::with_capacity(value.len());
+    let mut bytes = value.bytes();
+
+    while let Some(byte) = bytes.next() {
+        if byte != b'%' {
+            decoded.push(byte);
+            continue;
+        }
+
+        let escape: Vec<u8> = bytes.by_ref().take(2).collect();
+
+        match std::str::from_utf8(&escape)
+            .ok()
+            .filter(|hex| hex.len() == 2)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            Some(chr) => decoded.push(chr),
+            None => {
+                return Err(ProgrammingError::new_err(format!(
+                    "invalid percent-encoding in URI component '{}'",
+                    value
+                )))
+            }
+        }
+    }
+
+    String::from_utf8(decoded).map_err(|_| {
+        ProgrammingError::new_err(format!("URI component '{}' isn't valid UTF-8", value))
+    })
+}
+
+// </editor-fold desc="// Helpers ...">
+
+// <editor-fold desc="// Tests ...">
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use pyo3::Python;
+
+    use super::OpenOptions;
+    use crate::enums::{DeletedRecords, Encoding, Version, Whitespace};
+    use crate::exceptions::{NotSupportedError, ProgrammingError};
+
+    #[test]
+    /// Test that `OpenOptions` are correctly parsed from URIs
+    fn parses_uris() {
+        let (path, options) = OpenOptions::from_uri(
+            "file:/data/df%20files?mode=ro&encoding=latin1&encoding.Customer=cp850&whitespace=preserve&tags=/other/dir&tags=/more&tag_depth=1&lock=none&deleted=include&strict=1&filelist=2.3b",
+        )
+        .unwrap();
+
+        assert_eq!(path, "/data/df files");
+        assert_eq!(options.encoding_for("/data/customer.dat"), Encoding::Cp850);
+        assert_eq!(options.encoding_for("/data/vendor.dat"), Encoding::Latin1);
+        assert_eq!(
+            options,
+            OpenOptions {
+                read_only: true,
+                encoding: Encoding::Latin1,
+                table_encodings: BTreeMap::from([("CUSTOMER".to_string(), Encoding::Cp850)]),
+                whitespace: Whitespace::Preserve,
+                tag_paths: vec![PathBuf::from("/other/dir"), PathBuf::from("/more")],
+                tag_depth: 1,
+                deleted_records: DeletedRecords::Include,
+                strict: true,
+                filelist_version: Version::V23B,
+            }
+        );
+
+        let (path, options) = OpenOptions::from_uri("file:///C:/dataflex").unwrap();
+
+        assert_eq!(path, "C:/dataflex");
+        assert_eq!(options, OpenOptions::default());
+
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            for (uri, unsupported) in [
+                ("file:/data?lock=shared", true),
+                ("file:/data?lock=EXCLUSIVE", true),
+                ("file:/data?lock=sometimes", false),
+            ] {
+                let error = OpenOptions::from_uri(uri).unwrap_err();
+
+                assert_eq!(error.is_instance::<NotSupportedError>(py), unsupported);
+                assert_eq!(error.is_instance::<ProgrammingError>(py), !unsupported);
+            }
+        });
+    }
+}
+
+// </editor-fold desc="// Tests ...">
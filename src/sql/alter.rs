@@ -1,40 +1,65 @@
-// // Implementation of GlueSQL's optional `AlterTable` trait for DataFlex table files
-//
-// // Third-Party Imports
-// use async_trait::async_trait;
-// use gluesql::core::ast::ColumnDef;
-// use gluesql::core::result::MutResult as MutSqlResult;
-// use gluesql::core::store::AlterTable;
-//
-// // Crate-Level Imports
-// use crate::structs::database::DataFlexDB;
-//
-// #[allow(unused_variables)]
-// #[async_trait]
-// impl AlterTable for DataFlexDB {
-//     async fn rename_schema(self, table_name: &str, new_table_name: &str) -> MutSqlResult<Self, ()> {
-//         todo!()
-//     }
-//
-//     async fn rename_column(
-//         self,
-//         table_name: &str,
-//         old_column_name: &str,
-//         new_column_name: &str,
-//     ) -> MutSqlResult<Self, ()> {
-//         todo!()
-//     }
-//
-//     async fn add_column(self, table_name: &str, column_def: &ColumnDef) -> MutSqlResult<Self, ()> {
-//         todo!()
-//     }
-//
-//     async fn drop_column(
-//         self,
-//         table_name: &str,
-//         column_name: &str,
-//         if_exists: bool,
-//     ) -> MutSqlResult<Self, ()> {
-//         todo!()
-//     }
-// }
+// Implementation of GlueSQL's optional `AlterTable` trait for DataFlex table files
+
+// Third-Party Imports
+use async_trait::async_trait;
+use gluesql::core::ast::ColumnDef;
+use gluesql::core::result::MutResult as MutSqlResult;
+use gluesql::core::store::AlterTable;
+
+// Crate-Level Imports
+use crate::sql::{ensure_writable, StorageError};
+use crate::structs::database::DataFlexDB;
+
+// DataFlex table layouts are fixed by their headers (and mirrored
+// in each table's tag file and .FD/.DEF definitions), which ferroflex
+// can't yet rewrite safely. Every `ALTER TABLE` is rejected up front
+// rather than risking a partially-restructured table.
+#[allow(unused_variables)]
+#[async_trait(?Send)]
+impl AlterTable for DataFlexDB {
+    async fn rename_schema(self, table_name: &str, new_table_name: &str) -> MutSqlResult<Self, ()> {
+        let (db, ()) = ensure_writable(self)?;
+
+        Err((
+            db,
+            StorageError::Unsupported("ALTER TABLE ... RENAME TO".to_string()).into(),
+        ))
+    }
+
+    async fn rename_column(
+        self,
+        table_name: &str,
+        old_column_name: &str,
+        new_column_name: &str,
+    ) -> MutSqlResult<Self, ()> {
+        let (db, ()) = ensure_writable(self)?;
+
+        Err((
+            db,
+            StorageError::Unsupported("ALTER TABLE ... RENAME COLUMN".to_string()).into(),
+        ))
+    }
+
+    async fn add_column(self, table_name: &str, column_def: &ColumnDef) -> MutSqlResult<Self, ()> {
+        let (db, ()) = ensure_writable(self)?;
+
+        Err((
+            db,
+            StorageError::Unsupported("ALTER TABLE ... ADD COLUMN".to_string()).into(),
+        ))
+    }
+
+    async fn drop_column(
+        self,
+        table_name: &str,
+        column_name: &str,
+        if_exists: bool,
+    ) -> MutSqlResult<Self, ()> {
+        let (db, ()) = ensure_writable(self)?;
+
+        Err((
+            db,
+            StorageError::Unsupported("ALTER TABLE ... DROP COLUMN".to_string()).into(),
+        ))
+    }
+}
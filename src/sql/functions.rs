@@ -0,0 +1,1125 @@
+// Support for Python-defined SQL functions and aggregates
+//
+// GlueSQL has no notion of user-defined functions, so queries that call
+// one are rewritten before they're executed. Calls whose arguments are
+// all literals are evaluated up front and replaced by their result. Calls
+// that make up a whole item in the query's select list are replaced by
+// their arguments and evaluated against each row (or each group of rows,
+// for aggregates) of the result set. Any other scalar calls (e.g. in WHERE,
+// ORDER BY, or GROUP BY clauses, or nested in other expressions) are
+// evaluated once for each distinct set of arguments found in the query's
+// tables and replaced by a CASE expression that maps those arguments onto
+// the function's results.
+
+// Standard Library Imports
+use std::collections::HashMap;
+
+// Third-Party Imports
+use gluesql::core::data::{Row, Value};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+// Crate-Level Imports
+use crate::dbapi::{py_from_value, value_from_py};
+use crate::enums::Encoding;
+use crate::exceptions::{NotSupportedError, OperationalError, ProgrammingError};
+use crate::iif;
+use crate::sql::params::sql_literal_from;
+
+// <editor-fold desc="// Custom Types ...">
+
+/// Registered user-defined functions, keyed by their upper-cased name
+pub(crate) type FunctionRegistry = HashMap<String, UserFunction>;
+
+// </editor-fold desc="// Custom Types ...">
+
+// <editor-fold desc="// UserFunction ...">
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// The "kind" of a user-defined function
+pub(crate) enum FunctionKind {
+    /// A callable that's invoked once per row
+    Scalar,
+    /// A class implementing the `step` / `finalize`
+    /// protocol that's instantiated once per group
+    Aggregate,
+}
+
+#[derive(Clone, Debug)]
+/// A Python callable registered as a SQL function
+pub(crate) struct UserFunction {
+    /// The function's name as registered by the user
+    pub name: String,
+    /// The number of arguments the function
+    /// accepts (-1 meaning any number)
+    pub num_params: i32,
+    /// The function's "kind"
+    pub kind: FunctionKind,
+    /// The Python callable (or aggregate class)
+    pub callable: PyObject,
+    /// The encoding used to decode `bytes` results
+    pub encoding: Encoding,
+}
+
+impl UserFunction {
+    /// Raise a `ProgrammingError` if the function can't accept `count` arguments
+    fn _check_arity(&self, count: usize) -> PyResult<()> {
+        if self.num_params > -1 && self.num_params as usize != count {
+            return Err(ProgrammingError::new_err(format!(
+                "wrong number of arguments to function {}()",
+                self.name
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Wrap an exception raised by the function's Python code
+    fn _wrap_error(&self, py: Python, error: PyErr) -> PyErr {
+        OperationalError::new_err(format!(
+            "user-defined function {}() raised exception: {}",
+            self.name,
+            error.pvalue(py)
+        ))
+    }
+
+    /// Name the function in an error raised while converting its result
+    fn _wrap_result_error(&self, py: Python, error: PyErr) -> PyErr {
+        PyErr::from_type(
+            error.ptype(py),
+            format!(
+                "user-defined function {}() returned an unusable value - {}",
+                self.name,
+                error.pvalue(py)
+            ),
+        )
+    }
+
+    /// Convert a value returned by the function's Python code
+    fn _result(&self, py: Python, result: &PyAny) -> PyResult<Value> {
+        value_from_py(result, &self.encoding).map_err(|error| self._wrap_result_error(py, error))
+    }
+
+    /// Call a scalar function with the supplied arguments
+    fn call(&self, py: Python, args: &[Value]) -> PyResult<Value> {
+        let args = PyTuple::new(
+            py,
+            args.iter()
+                .map(|arg| py_from_value(py, arg))
+                .collect::<PyResult<Vec<PyObject>>>()?,
+        );
+
+        let result = self
+            .callable
+            .call1(py, args)
+            .map_err(|error| self._wrap_error(py, error))?;
+
+        self._result(py, result.as_ref(py))
+    }
+
+    /// Fold the supplied rows of arguments through a new
+    /// instance of an aggregate class and return the result
+    fn aggregate<'a, I>(&self, py: Python, rows: I) -> PyResult<Value>
+    where
+        I: Iterator<Item = &'a [Value]>,
+    {
+        let instance = self
+            .callable
+            .call0(py)
+            .map_err(|error| self._wrap_error(py, error))?;
+
+        for args in rows {
+            let args = PyTuple::new(
+                py,
+                args.iter()
+                    .map(|arg| py_from_value(py, arg))
+                    .collect::<PyResult<Vec<PyObject>>>()?,
+            );
+
+            instance
+                .call_method1(py, "step", args)
+                .map_err(|error| self._wrap_error(py, error))?;
+        }
+
+        let result = instance
+            .call_method0(py, "finalize")
+            .map_err(|error| self._wrap_error(py, error))?;
+
+        self._result(py, result.as_ref(py))
+    }
+}
+
+// </editor-fold desc="// UserFunction ...">
+
+// <editor-fold desc="// Tokenizer ...">
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// The "kind" of a single SQL token
+enum TokenKind {
+    /// A keyword or (unquoted) identifier
+    Word,
+    /// A numeric literal
+    Number,
+    /// A single-quoted string literal
+    Text,
+    /// A double-quoted or back-ticked identifier
+    Identifier,
+    /// Any single punctuation character
+    Symbol(char),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A single SQL token and its location in the original SQL
+struct Token {
+    kind: TokenKind,
+    /// The byte offset at which the token starts
+    start: usize,
+    /// The byte offset immediately after the token
+    end: usize,
+    /// The parenthetical nesting depth of the token
+    depth: usize,
+}
+
+impl Token {
+    fn text<'a>(&self, sql: &'a str) -> &'a str {
+        &sql[self.start..self.end]
+    }
+
+    fn is_word(&self, sql: &str, word: &str) -> bool {
+        self.kind == TokenKind::Word && self.text(sql).eq_ignore_ascii_case(word)
+    }
+}
+
+/// Split SQL into tokens, skipping whitespace and comments
+fn tokenize(sql: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut depth: usize = 0;
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((start, chr)) = chars.next() {
+        let kind = match chr {
+            _ if chr.is_whitespace() => continue,
+            '-' if matches!(chars.peek(), Some((_, '-'))) => {
+                for (_, next) in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+                continue;
+            }
+            '\'' | '"' | '`' => {
+                while let Some((_, next)) = chars.next() {
+                    if next == chr {
+                        // A doubled quote is an escaped quote
+                        if matches!(chars.peek(), Some((_, quote)) if *quote == chr) {
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                if chr == '\'' {
+                    TokenKind::Text
+                } else {
+                    TokenKind::Identifier
+                }
+            }
+            _ if chr.is_ascii_digit() => {
+                while let Some((_, next)) = chars.peek() {
+                    if next.is_ascii_digit() || *next == '.' {
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                TokenKind::Number
+            }
+            _ if chr.is_alphabetic() || chr == '_' => {
+                while let Some((_, next)) = chars.peek() {
+                    if next.is_alphanumeric() || *next == '_' || *next == '.' {
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                TokenKind::Word
+            }
+            _ => TokenKind::Symbol(chr),
+        };
+
+        let end: usize = match chars.peek() {
+            Some((offset, _)) => *offset,
+            None => sql.len(),
+        };
+
+        if kind == TokenKind::Symbol(')') {
+            depth = depth.saturating_sub(1);
+        }
+
+        tokens.push(Token {
+            kind: kind.clone(),
+            start,
+            end,
+            depth,
+        });
+
+        if kind == TokenKind::Symbol('(') {
+            depth += 1;
+        }
+    }
+
+    tokens
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A call to a user-defined function found in some SQL
+struct Call {
+    /// The (upper-cased) name of the called function
+    name: String,
+    /// The index of the token holding the function's name
+    first: usize,
+    /// The index of the call's closing parenthesis
+    last: usize,
+    /// The (start, end) token indexes of each of the
+    /// call's arguments (both ends inclusive)
+    args: Vec<(usize, usize)>,
+}
+
+/// Find the outer-most calls to any of the registered functions
+fn find_calls(tokens: &[Token], sql: &str, registry: &FunctionRegistry) -> Vec<Call> {
+    let mut calls: Vec<Call> = Vec::new();
+    let mut idx: usize = 0;
+
+    while idx + 1 < tokens.len() {
+        let (token, next) = (&tokens[idx], &tokens[idx + 1]);
+        let name: String = token.text(sql).to_uppercase();
+
+        if token.kind != TokenKind::Word
+            || next.kind != TokenKind::Symbol('(')
+            || !registry.contains_key(&name)
+        {
+            idx += 1;
+            continue;
+        }
+
+        let mut args: Vec<(usize, usize)> = Vec::new();
+        let mut arg_start: usize = idx + 2;
+        let mut last: usize = idx + 2;
+
+        while last < tokens.len() {
+            let current = &tokens[last];
+
+            if current.depth == token.depth + 1 && current.kind == TokenKind::Symbol(',') {
+                args.push((arg_start, last - 1));
+                arg_start = last + 1;
+            } else if current.depth == token.depth && current.kind == TokenKind::Symbol(')') {
+                break;
+            }
+
+            last += 1;
+        }
+
+        if arg_start < last {
+            args.push((arg_start, last - 1));
+        }
+
+        calls.push(Call {
+            name,
+            first: idx,
+            last,
+            args,
+        });
+
+        idx = last + 1;
+    }
+
+    calls
+}
+
+/// Get the Python value of an argument made up solely of a literal
+fn literal_value(py: Python, tokens: &[Token], sql: &str) -> Option<PyObject> {
+    let (negative, tokens) = match tokens {
+        [sign, rest @ ..] if sign.kind == TokenKind::Symbol('-') => (true, rest),
+        _ => (false, tokens),
+    };
+
+    match tokens {
+        [token] => match token.kind {
+            TokenKind::Number => {
+                let text = token.text(sql);
+                let text = if negative {
+                    format!("-{}", text)
+                } else {
+                    text.to_string()
+                };
+
+                match text.parse::<i64>() {
+                    Ok(number) => Some(number.to_object(py)),
+                    Err(_) => text.parse::<f64>().ok().map(|number| number.to_object(py)),
+                }
+            }
+            TokenKind::Text if !negative => {
+                let text = token.text(sql);
+
+                Some(text[1..text.len() - 1].replace("''", "'").to_object(py))
+            }
+            TokenKind::Word if !negative => match token.text(sql).to_uppercase().as_str() {
+                "NULL" => Some(py.None()),
+                "TRUE" => Some(true.to_object(py)),
+                "FALSE" => Some(false.to_object(py)),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// </editor-fold desc="// Tokenizer ...">
+
+// <editor-fold desc="// FunctionPlan ...">
+
+#[derive(Clone, Debug)]
+/// A single item of a rewritten query's select list
+enum Output {
+    /// A plain select list item (`*` items may
+    /// produce any number of columns)
+    Columns { star: bool },
+    /// A call to a user-defined function whose arguments
+    /// have been moved to the end of the select list
+    Call {
+        function: UserFunction,
+        /// The indexes of the call's arguments within the
+        /// arguments appended to the select list
+        args: Vec<usize>,
+        /// The label of the call's result column
+        label: String,
+    },
+}
+
+#[derive(Clone, Debug)]
+/// A query that's been rewritten so that GlueSQL can execute
+/// it, along with what's needed to evaluate its user-defined
+/// function calls against the rows that it produces
+pub(crate) struct FunctionPlan {
+    /// The rewritten SQL
+    pub sql: String,
+    /// The items of the original select list
+    outputs: Vec<Output>,
+    /// The number of extra columns appended to the select list
+    extra_columns: usize,
+    /// Indicates that the plan includes aggregate functions
+    aggregate: bool,
+}
+
+impl FunctionPlan {
+    /// Indicates that `sql` calls any of the registered functions
+    pub(crate) fn calls_any(sql: &str, registry: &FunctionRegistry) -> bool {
+        !registry.is_empty() && !find_calls(&tokenize(sql), sql, registry).is_empty()
+    }
+
+    /// Rewrite `sql` to remove any calls to registered functions, using
+    /// `execute` to run any queries needed to evaluate calls that aren't
+    /// part of the select list. Returns `None` if none of the functions
+    /// are called.
+    pub(crate) fn new(
+        py: Python,
+        sql: &str,
+        registry: &FunctionRegistry,
+        execute: &dyn Fn(&str) -> PyResult<Vec<Vec<Value>>>,
+    ) -> PyResult<Option<FunctionPlan>> {
+        if !FunctionPlan::calls_any(sql, registry) {
+            return Ok(None);
+        }
+
+        // Evaluate any scalar calls that only involve literals
+        let sql: String = FunctionPlan::_fold_constants(py, sql, &tokenize(sql), registry)?;
+
+        // Then any that can't be evaluated against the result set
+        let sql: String = FunctionPlan::_inline_calls(py, sql, registry, execute)?;
+        let tokens: Vec<Token> = tokenize(&sql);
+
+        let calls: Vec<Call> = find_calls(&tokens, &sql, registry);
+
+        if calls.is_empty() {
+            return Ok(Some(FunctionPlan {
+                sql,
+                outputs: Vec::new(),
+                extra_columns: 0,
+                aggregate: false,
+            }));
+        }
+
+        FunctionPlan::_rewrite_select_list(&sql, &tokens, &calls, registry).map(Some)
+    }
+
+    /// Replace every call whose arguments are all literals with its result
+    fn _fold_constants(
+        py: Python,
+        sql: &str,
+        tokens: &[Token],
+        registry: &FunctionRegistry,
+    ) -> PyResult<String> {
+        let mut folded: String = String::new();
+        let mut position: usize = 0;
+
+        for call in find_calls(tokens, sql, registry) {
+            let function = &registry[&call.name];
+
+            if function.kind != FunctionKind::Scalar {
+                continue;
+            }
+
+            let args: Option<Vec<PyObject>> = call
+                .args
+                .iter()
+                .map(|(first, last)| literal_value(py, &tokens[*first..=*last], sql))
+                .collect();
+
+            if let Some(args) = args {
+                function._check_arity(args.len())?;
+
+                let result = function
+                    .callable
+                    .call1(py, PyTuple::new(py, args))
+                    .map_err(|error| function._wrap_error(py, error))?;
+
+                folded.push_str(&sql[position..tokens[call.first].start]);
+                let result: Value = function._result(py, result.as_ref(py))?;
+
+                folded.push_str(
+                    &sql_literal_from(&result)
+                        .map_err(|error| function._wrap_result_error(py, error))?,
+                );
+                position = tokens[call.last].end;
+            }
+        }
+
+        folded.push_str(&sql[position..]);
+
+        Ok(folded)
+    }
+
+    /// Replace each scalar call that isn't a whole item of the select list
+    /// with a CASE expression mapping each distinct set of its arguments
+    /// (as found in the tables the query selects from) onto its result
+    fn _inline_calls(
+        py: Python,
+        mut sql: String,
+        registry: &FunctionRegistry,
+        execute: &dyn Fn(&str) -> PyResult<Vec<Vec<Value>>>,
+    ) -> PyResult<String> {
+        loop {
+            let tokens: Vec<Token> = tokenize(&sql);
+            let items: Option<(usize, Vec<(usize, usize)>)> = select_items(&tokens, &sql);
+
+            // Find the first call that has to be inlined, preferring
+            // any calls nested in its arguments over the call itself
+            let whole_item = |call: &Call| {
+                items.as_ref().is_some_and(|(_, items)| {
+                    items
+                        .iter()
+                        .any(|(first, last)| is_whole_item(call, *first, *last, &tokens, &sql))
+                })
+            };
+
+            let mut target: Option<Call> = None;
+
+            for call in find_calls(&tokens, &sql, registry) {
+                let mut nested: Vec<Call> = nested_calls(&call, &tokens, &sql, registry);
+
+                if nested.is_empty() && whole_item(&call) {
+                    continue;
+                }
+
+                let mut call: Call = iif!(whole_item(&call), nested.remove(0), call);
+
+                while let Some(inner) = nested_calls(&call, &tokens, &sql, registry)
+                    .into_iter()
+                    .next()
+                {
+                    call = inner;
+                }
+
+                target = Some(call);
+                break;
+            }
+
+            let call: Call = match target {
+                Some(call) => call,
+                None => return Ok(sql),
+            };
+
+            let function: &UserFunction = &registry[&call.name];
+            let unsupported = |reason: &str| -> PyErr {
+                NotSupportedError::new_err(format!(
+                    "user-defined function {}() {}",
+                    function.name, reason
+                ))
+            };
+
+            if function.kind != FunctionKind::Scalar {
+                return Err(unsupported("must make up a whole item of the select list"));
+            }
+
+            let from: usize = match items {
+                Some((from, _)) if from < tokens.len() => from,
+                _ => {
+                    return Err(unsupported(
+                        "with non-literal arguments can only be used in SELECT statements",
+                    ))
+                }
+            };
+
+            if tokens
+                .iter()
+                .filter(|token| token.is_word(&sql, "SELECT"))
+                .count()
+                > 1
+            {
+                return Err(unsupported(
+                    "with non-literal arguments can't be used outside of the select list of a query with subqueries",
+                ));
+            }
+
+            function._check_arity(call.args.len())?;
+
+            let args: Vec<&str> = call
+                .args
+                .iter()
+                .map(|(first, last)| &sql[tokens[*first].start..tokens[*last].end])
+                .collect();
+
+            let source_end: usize = tokens
+                .iter()
+                .skip(from + 1)
+                .find(|token| {
+                    token.depth == 0
+                        && (token.kind == TokenKind::Symbol(';')
+                            || ["WHERE", "GROUP", "HAVING", "ORDER", "LIMIT", "OFFSET"]
+                                .iter()
+                                .any(|word| token.is_word(&sql, word)))
+                })
+                .map_or(sql.len(), |token| token.start);
+
+            let mut cases: Vec<String> = Vec::new();
+            let mut seen: Vec<Vec<Value>> = Vec::new();
+
+            if !args.is_empty() {
+                let rows: Vec<Vec<Value>> = execute(&format!(
+                    "SELECT {} {}",
+                    args.join(", "),
+                    &sql[tokens[from].start..source_end]
+                ))?;
+
+                for row in rows {
+                    if seen.contains(&row) {
+                        continue;
+                    }
+
+                    let conditions: Vec<String> = args
+                        .iter()
+                        .zip(row.iter())
+                        .map(|(arg, value)| match value {
+                            Value::Null => Ok(format!("({}) IS NULL", arg)),
+                            _ => Ok(format!("({}) = {}", arg, sql_literal_from(value)?)),
+                        })
+                        .collect::<PyResult<Vec<String>>>()?;
+
+                    let result: Value = function.call(py, &row)?;
+
+                    cases.push(format!(
+                        "WHEN {} THEN {}",
+                        conditions.join(" AND "),
+                        sql_literal_from(&result)
+                            .map_err(|error| function._wrap_result_error(py, error))?
+                    ));
+                    seen.push(row);
+                }
+            }
+
+            let replacement: String = iif!(
+                cases.is_empty(),
+                "NULL".to_string(),
+                format!("(CASE {} ELSE NULL END)", cases.join(" "))
+            );
+
+            sql = format!(
+                "{}{}{}",
+                &sql[..tokens[call.first].start],
+                replacement,
+                &sql[tokens[call.last].end..]
+            );
+        }
+    }
+
+    /// Move the arguments of each call in the select list to the end of
+    /// the select list so that the calls can be evaluated after execution
+    fn _rewrite_select_list(
+        sql: &str,
+        tokens: &[Token],
+        calls: &[Call],
+        registry: &FunctionRegistry,
+    ) -> PyResult<FunctionPlan> {
+        let unsupported = |reason: &str| -> PyErr {
+            NotSupportedError::new_err(format!(
+                "user-defined functions {} (called: {})",
+                reason,
+                calls
+                    .iter()
+                    .map(|call| registry[&call.name].name.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ))
+        };
+
+        let top_level = |word: &str| -> Option<usize> {
+            tokens
+                .iter()
+                .position(|token| token.depth == 0 && token.is_word(sql, word))
+        };
+
+        // Find the bounds of the select list
+        let select: usize = match top_level("SELECT") {
+            Some(idx) => idx + 1,
+            None => return Err(unsupported("can only be used in SELECT statements")),
+        };
+        let from: usize = top_level("FROM").unwrap_or(tokens.len());
+
+        if from <= select {
+            return Err(unsupported("can only be used in SELECT statements"));
+        }
+
+        if tokens[select].is_word(sql, "DISTINCT") {
+            return Err(unsupported("can't be combined with SELECT DISTINCT"));
+        }
+
+        if calls
+            .iter()
+            .any(|call| call.first < select || call.last >= from)
+        {
+            return Err(unsupported(
+                "with non-literal arguments can only be used in the select list",
+            ));
+        }
+
+        // Split the select list into its individual items
+        let mut items: Vec<(usize, usize)> = Vec::new();
+        let mut item_start: usize = select;
+
+        for (idx, token) in tokens.iter().enumerate().take(from).skip(select) {
+            if token.depth == 0 && token.kind == TokenKind::Symbol(',') {
+                items.push((item_start, idx - 1));
+                item_start = idx + 1;
+            }
+        }
+
+        items.push((item_start, from - 1));
+
+        let mut outputs: Vec<Output> = Vec::new();
+        let mut kept: Vec<String> = Vec::new();
+        let mut appended: Vec<String> = Vec::new();
+
+        for (first, last) in items {
+            let text: &str = &sql[tokens[first].start..tokens[last].end];
+
+            let call = match calls.iter().find(|call| call.first == first) {
+                Some(call) => call,
+                None => {
+                    if calls
+                        .iter()
+                        .any(|call| first < call.first && call.last <= last)
+                    {
+                        return Err(unsupported("must make up a whole item of the select list"));
+                    }
+
+                    outputs.push(Output::Columns {
+                        star: tokens[last].kind == TokenKind::Symbol('*'),
+                    });
+                    kept.push(text.to_string());
+                    continue;
+                }
+            };
+
+            // Anything after the call has to be an alias
+            let label: String = match &tokens[call.last + 1..=last] {
+                [] => sql[tokens[call.first].start..tokens[call.last].end].to_string(),
+                [alias] => alias_text(alias, sql),
+                [keyword, alias] if keyword.is_word(sql, "AS") => alias_text(alias, sql),
+                _ => return Err(unsupported("must make up a whole item of the select list")),
+            };
+
+            let function: &UserFunction = &registry[&call.name];
+
+            function._check_arity(call.args.len())?;
+
+            let args: Vec<usize> = call
+                .args
+                .iter()
+                .map(|(arg_first, arg_last)| {
+                    appended.push(sql[tokens[*arg_first].start..tokens[*arg_last].end].to_string());
+                    appended.len() - 1
+                })
+                .collect();
+
+            outputs.push(Output::Call {
+                function: function.clone(),
+                args,
+                label,
+            });
+        }
+
+        let aggregate: bool = outputs.iter().any(|output| {
+            matches!(output, Output::Call { function, .. } if function.kind == FunctionKind::Aggregate)
+        });
+
+        if aggregate {
+            if outputs.iter().any(|output| {
+                matches!(output, Output::Call { function, .. } if function.kind == FunctionKind::Scalar)
+            }) {
+                return Err(unsupported(
+                    "can't mix scalar and aggregate calls with non-literal arguments",
+                ));
+            }
+
+            if kept.iter().any(|item| {
+                let item = item.to_uppercase();
+
+                ["COUNT(", "SUM(", "AVG(", "MIN(", "MAX("]
+                    .iter()
+                    .any(|builtin| item.contains(builtin))
+            }) {
+                return Err(unsupported("can't be mixed with built-in aggregates"));
+            }
+
+            if ["HAVING", "LIMIT", "OFFSET"]
+                .iter()
+                .any(|word| top_level(word).is_some())
+            {
+                return Err(unsupported(
+                    "that aggregate can't be combined with HAVING, LIMIT, or OFFSET",
+                ));
+            }
+        }
+
+        if outputs
+            .iter()
+            .filter(|output| matches!(output, Output::Columns { star: true }))
+            .count()
+            > 1
+        {
+            return Err(unsupported("can't be combined with more than one `*`"));
+        }
+
+        // Rebuild the statement around the new select list
+        let mut columns: Vec<String> = kept;
+        let extra_columns: usize =
+            appended.len() + usize::from(columns.is_empty() && appended.is_empty());
+
+        columns.extend(appended);
+
+        if columns.is_empty() {
+            // Keep one column around so that every row is still returned
+            columns.push("1".to_string());
+        }
+
+        let tail_start: usize = if from < tokens.len() {
+            tokens[from].start
+        } else {
+            sql.len()
+        };
+
+        let mut tail: String = sql[tail_start..].to_string();
+
+        // Rows are grouped by the remaining select list items
+        // instead, once the aggregate calls have been evaluated
+        if aggregate {
+            if let Some(group) = top_level("GROUP") {
+                let group_end: usize = tokens
+                    .iter()
+                    .enumerate()
+                    .skip(group + 1)
+                    .find(|(_, token)| {
+                        token.depth == 0
+                            && (token.is_word(sql, "ORDER") || token.kind == TokenKind::Symbol(';'))
+                    })
+                    .map(|(_, token)| token.start)
+                    .unwrap_or(sql.len());
+
+                tail = format!(
+                    "{}{}",
+                    &sql[tail_start..tokens[group].start],
+                    &sql[group_end..]
+                );
+            }
+        }
+
+        Ok(FunctionPlan {
+            sql: format!(
+                "{}{} {}",
+                &sql[..tokens[select].start],
+                columns.join(", "),
+                tail
+            ),
+            outputs,
+            extra_columns,
+            aggregate,
+        })
+    }
+
+    /// Evaluate the plan's function calls against the rows
+    /// produced by executing its (rewritten) SQL
+    pub(crate) fn apply(
+        &self,
+        py: Python,
+        labels: Vec<String>,
+        rows: Vec<Row>,
+    ) -> PyResult<(Vec<String>, Vec<Row>)> {
+        if self.outputs.is_empty() {
+            return Ok((labels, rows));
+        }
+
+        let base: usize = labels.len() - self.extra_columns;
+        let fixed: usize = self
+            .outputs
+            .iter()
+            .filter(|output| matches!(output, Output::Columns { star: false }))
+            .count();
+        let star_width: usize = base.saturating_sub(fixed);
+
+        // Work out which of the executed columns belong to
+        // each of the original select list's items
+        let mut spans: Vec<(usize, usize)> = Vec::new();
+        let mut position: usize = 0;
+        let mut out_labels: Vec<String> = Vec::new();
+
+        for output in &self.outputs {
+            match output {
+                Output::Columns { star } => {
+                    let width: usize = if *star { star_width } else { 1 };
+
+                    spans.push((position, position + width));
+                    out_labels.extend(labels[position..position + width].iter().cloned());
+                    position += width;
+                }
+                Output::Call { label, .. } => {
+                    spans.push((position, position));
+                    out_labels.push(label.clone());
+                }
+            }
+        }
+
+        let arguments = |row: &Row, args: &[usize]| -> Vec<Value> {
+            args.iter().map(|arg| row.0[base + arg].clone()).collect()
+        };
+
+        if !self.aggregate {
+            let rows = rows
+                .iter()
+                .map(|row| {
+                    let mut values: Vec<Value> = Vec::new();
+
+                    for (output, (start, end)) in self.outputs.iter().zip(spans.iter()) {
+                        match output {
+                            Output::Columns { .. } => {
+                                values.extend(row.0[*start..*end].iter().cloned())
+                            }
+                            Output::Call { function, args, .. } => {
+                                values.push(function.call(py, &arguments(row, args))?)
+                            }
+                        }
+                    }
+
+                    Ok(Row(values))
+                })
+                .collect::<PyResult<Vec<Row>>>()?;
+
+            return Ok((out_labels, rows));
+        }
+
+        // Group the rows by the values of the plain select list
+        // items (in the order in which each group is first seen)
+        let mut groups: Vec<(Vec<Value>, Vec<&Row>)> = Vec::new();
+
+        for row in &rows {
+            let key: Vec<Value> = row.0[..base].to_vec();
+
+            match groups.iter_mut().find(|(existing, _)| *existing == key) {
+                Some((_, members)) => members.push(row),
+                None => groups.push((key, vec![row])),
+            }
+        }
+
+        // Aggregates over an ungrouped, empty result still produce a row
+        if groups.is_empty() && base == 0 {
+            groups.push((Vec::new(), Vec::new()));
+        }
+
+        let rows = groups
+            .iter()
+            .map(|(key, members)| {
+                let mut values: Vec<Value> = Vec::new();
+
+                for (output, (start, end)) in self.outputs.iter().zip(spans.iter()) {
+                    match output {
+                        Output::Columns { .. } => values.extend(key[*start..*end].iter().cloned()),
+                        Output::Call { function, args, .. } => {
+                            let args: Vec<Vec<Value>> =
+                                members.iter().map(|row| arguments(row, args)).collect();
+
+                            values.push(
+                                function.aggregate(py, args.iter().map(|row| row.as_slice()))?,
+                            );
+                        }
+                    }
+                }
+
+                Ok(Row(values))
+            })
+            .collect::<PyResult<Vec<Row>>>()?;
+
+        Ok((out_labels, rows))
+    }
+}
+
+/// Find the start of a query's FROM clause and the (first, last)
+/// token indexes of each item of its select list
+fn select_items(tokens: &[Token], sql: &str) -> Option<(usize, Vec<(usize, usize)>)> {
+    let top_level = |word: &str| -> Option<usize> {
+        tokens
+            .iter()
+            .position(|token| token.depth == 0 && token.is_word(sql, word))
+    };
+
+    let select: usize = top_level("SELECT")? + 1;
+    let from: usize = top_level("FROM").unwrap_or(tokens.len());
+
+    if from <= select {
+        return None;
+    }
+
+    let mut items: Vec<(usize, usize)> = Vec::new();
+    let mut item_start: usize = select;
+
+    for (idx, token) in tokens.iter().enumerate().take(from).skip(select) {
+        if token.depth == 0 && token.kind == TokenKind::Symbol(',') {
+            items.push((item_start, idx - 1));
+            item_start = idx + 1;
+        }
+    }
+
+    items.push((item_start, from - 1));
+
+    Some((from, items))
+}
+
+/// Indicates that `call` makes up the whole of (an
+/// optionally aliased) select list item
+fn is_whole_item(call: &Call, first: usize, last: usize, tokens: &[Token], sql: &str) -> bool {
+    call.first == first
+        && call.last <= last
+        && match &tokens[call.last + 1..=last] {
+            [] | [_] => true,
+            [keyword, _] => keyword.is_word(sql, "AS"),
+            _ => false,
+        }
+}
+
+/// Find the outer-most calls to any of the registered
+/// functions within the arguments of `call`
+fn nested_calls(
+    call: &Call,
+    tokens: &[Token],
+    sql: &str,
+    registry: &FunctionRegistry,
+) -> Vec<Call> {
+    let offset: usize = call.first + 2;
+
+    find_calls(&tokens[offset..call.last], sql, registry)
+        .into_iter()
+        .map(|nested| Call {
+            first: nested.first + offset,
+            last: nested.last + offset,
+            args: nested
+                .args
+                .iter()
+                .map(|(first, last)| (first + offset, last + offset))
+                .collect(),
+            ..nested
+        })
+        .collect()
+}
+
+/// Get the text of an alias token (without any quotes)
+fn alias_text(token: &Token, sql: &str) -> String {
+    let text = token.text(sql);
+
+    match token.kind {
+        TokenKind::Identifier | TokenKind::Text => text[1..text.len() - 1].to_string(),
+        _ => text.to_string(),
+    }
+}
+
+// </editor-fold desc="// FunctionPlan ...">
+
+// <editor-fold desc="// Tests ...">
+
+#[cfg(test)]
+mod tests {
+    use super::{is_whole_item, select_items, tokenize, Call, TokenKind};
+
+    #[test]
+    /// Test that SQL is tokenized with correct nesting depths
+    fn tokenizes_sql() {
+        let sql = "SELECT f(a, 'it''s'), b -- note\nFROM t";
+        let tokens = tokenize(sql);
+
+        let kinds: Vec<TokenKind> = tokens.iter().map(|token| token.kind.clone()).collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Word,
+                TokenKind::Word,
+                TokenKind::Symbol('('),
+                TokenKind::Word,
+                TokenKind::Symbol(','),
+                TokenKind::Text,
+                TokenKind::Symbol(')'),
+                TokenKind::Symbol(','),
+                TokenKind::Word,
+                TokenKind::Word,
+                TokenKind::Word,
+            ]
+        );
+
+        assert_eq!(tokens[3].depth, 1);
+        assert_eq!(tokens[5].text(sql), "'it''s'");
+        assert_eq!(tokens[6].depth, 0);
+        assert_eq!(tokens[10].text(sql), "t");
+    }
+
+    #[test]
+    /// Test that calls are only treated as select list items when they make up a whole item
+    fn finds_whole_select_items() {
+        let sql = "SELECT f(a) AS x, f(b) + 1, c FROM t WHERE f(c) = 1";
+        let tokens = tokenize(sql);
+        let (from, items) = select_items(&tokens, sql).unwrap();
+
+        assert_eq!(tokens[from].text(sql), "FROM");
+        assert_eq!(items, vec![(1, 6), (8, 13), (15, 15)]);
+
+        let call = |first: usize, last: usize| Call {
+            name: "F".to_string(),
+            first,
+            last,
+            args: vec![(first + 2, last - 1)],
+        };
+
+        assert!(is_whole_item(&call(1, 4), 1, 6, &tokens, sql));
+        assert!(!is_whole_item(&call(8, 11), 8, 13, &tokens, sql));
+        assert!(select_items(&tokenize("DELETE FROM t"), "DELETE FROM t").is_none());
+    }
+}
+
+// </editor-fold desc="// Tests ...">
@@ -1,11 +1,32 @@
 // ferroflex's bindings to [GlueSQL](https://github.com/gluesql/gluesql)
 
 mod alter;
+mod functions;
 mod index;
 mod metadata;
+mod params;
 mod store;
 mod transaction;
 
+// Standard Library Imports
+use std::error::Error;
+use std::fmt;
+use std::iter;
+
+// Sub-Module "Exports"
+pub(crate) use functions::{FunctionKind, FunctionPlan, FunctionRegistry, UserFunction};
+pub use params::PreparedStatement;
+
+// Third-Party Imports
+use gluesql::core::ast::{SetExpr, Statement, TableFactor};
+use gluesql::core::parse_sql::parse;
+use gluesql::core::result::Error as SqlError;
+use gluesql::core::result::MutResult as MutSqlResult;
+use gluesql::core::translate::translate;
+
+// Crate-Level Imports
+use crate::structs::DataFlexDB;
+
 // // Third-Party Imports
 // use pyo3::types::PyModule;
 // use pyo3::{PyResult, Python};
@@ -31,3 +52,100 @@ mod transaction;
 // }
 //
 // // </editor-fold desc="// Component Registration ...">
+
+// <editor-fold desc="// Storage Errors ...">
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// Errors raised from within ferroflex's GlueSQL storage implementation
+pub(crate) enum StorageError {
+    /// The running scan was cancelled by `Connection.interrupt()`
+    Interrupted,
+    /// A write was attempted against a read-only database
+    ReadOnly,
+    /// The requested operation isn't supported by the storage layer
+    Unsupported(String),
+    /// A table's record couldn't be read (or decoded)
+    Unreadable(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StorageError::Interrupted => write!(f, "interrupted"),
+            StorageError::ReadOnly => write!(f, "attempt to write a read-only database"),
+            StorageError::Unsupported(operation) => write!(f, "{} is not supported", operation),
+            StorageError::Unreadable(reason) => write!(f, "unreadable record: {}", reason),
+        }
+    }
+}
+
+impl Error for StorageError {}
+
+impl From<StorageError> for SqlError {
+    fn from(error: StorageError) -> Self {
+        SqlError::Storage(Box::new(error))
+    }
+}
+
+impl StorageError {
+    /// Recover a `StorageError` from a GlueSQL error (if it wraps one)
+    pub(crate) fn from_sql_error(error: &SqlError) -> Option<&StorageError> {
+        match error {
+            SqlError::Storage(inner) => inner.downcast_ref::<StorageError>(),
+            _ => None,
+        }
+    }
+}
+
+// </editor-fold desc="// Storage Errors ...">
+
+// <editor-fold desc="// Helpers ...">
+
+/// Fail with a `StorageError::ReadOnly` if the database
+/// was opened in read-only mode (returning GlueSQL's own
+/// `MutResult` so the store methods can simply use `?`)
+#[allow(clippy::result_large_err)]
+pub(crate) fn ensure_writable(db: DataFlexDB) -> MutSqlResult<DataFlexDB, ()> {
+    if db.options.read_only {
+        return Err((db, StorageError::ReadOnly.into()));
+    }
+
+    Ok((db, ()))
+}
+
+/// Get the name of the table referred to by a `TableFactor`
+fn table_factor_name(factor: &TableFactor) -> String {
+    match factor {
+        TableFactor::Table { name, .. } => name.0.join("."),
+    }
+}
+
+/// Get the names of the tables referenced by the supplied SQL, in the
+/// order in which they're referenced. SQL that can't be parsed simply
+/// doesn't reference any tables.
+pub(crate) fn referenced_tables<T: AsRef<str>>(sql: T) -> Vec<String> {
+    let statements = match parse(sql.as_ref()) {
+        Ok(statements) => statements,
+        Err(_) => return Vec::new(),
+    };
+
+    statements
+        .iter()
+        .filter_map(|statement| translate(statement).ok())
+        .flat_map(|statement| match statement {
+            Statement::Query(query) => match &query.body {
+                SetExpr::Select(select) => iter::once(&select.from.relation)
+                    .chain(select.from.joins.iter().map(|join| &join.relation))
+                    .map(table_factor_name)
+                    .collect::<Vec<String>>(),
+                _ => Vec::new(),
+            },
+            Statement::Insert { table_name, .. }
+            | Statement::Update { table_name, .. }
+            | Statement::Delete { table_name, .. } => vec![table_name.0.join(".")],
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+// </editor-fold desc="// Helpers ...">
@@ -0,0 +1,476 @@
+// Binding of Python values to `qmark`-style SQL parameter placeholders
+
+// Third-Party Imports
+use gluesql::core::ast::{
+    Expr, JoinConstraint, JoinOperator, Query, SelectItem, SetExpr, Statement,
+};
+use gluesql::core::parse_sql::{parse, parse_expr};
+use gluesql::core::translate::{translate, translate_expr};
+use gluesql::prelude::Value;
+use pyo3::prelude::*;
+
+// Crate-Level Imports
+use crate::dbapi::value_from_py;
+use crate::enums::Encoding;
+use crate::exceptions::{py_err_from_sql, DataError, ProgrammingError};
+use crate::iif;
+
+/// The prefix of the identifiers that stand in for a statement's
+/// placeholders while it's parsed (see `PreparedStatement::parse`)
+const PLACEHOLDER_PREFIX: &str = "__FERROFLEX_PARAMETER_";
+
+// <editor-fold desc="// PreparedStatement ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq)]
+/// A SQL statement that has been split at each of its `?`
+/// placeholders so that it can be bound to any number of
+/// parameter sets without being re-scanned
+pub struct PreparedStatement {
+    /// The literal SQL surrounding each of the
+    /// statement's placeholders (there is always
+    /// exactly one more fragment than there are
+    /// placeholders)
+    fragments: Vec<String>,
+}
+
+impl<T: AsRef<str>> From<T> for PreparedStatement {
+    fn from(sql: T) -> Self {
+        let mut fragments: Vec<String> = Vec::new();
+        let mut current: String = String::new();
+        let mut quote: Option<char> = None;
+        let mut chars = sql.as_ref().chars().peekable();
+
+        while let Some(chr) = chars.next() {
+            match (quote, chr) {
+                // Placeholders only count outside of
+                // string literals and quoted identifiers
+                (None, '?') => fragments.push(std::mem::take(&mut current)),
+                (None, '\'' | '"' | '`') => {
+                    quote = Some(chr);
+                    current.push(chr);
+                }
+                // Line comments run to the end of the line
+                (None, '-') if chars.peek() == Some(&'-') => {
+                    current.push(chr);
+
+                    for next in chars.by_ref() {
+                        current.push(next);
+
+                        if next == '\n' {
+                            break;
+                        }
+                    }
+                }
+                (Some(open), _) if chr == open => {
+                    current.push(chr);
+
+                    // A doubled quote is an escaped quote
+                    if chars.peek() == Some(&open) {
+                        current.push(chars.next().unwrap_or(open));
+                    } else {
+                        quote = None;
+                    }
+                }
+                _ => current.push(chr),
+            }
+        }
+
+        fragments.push(current);
+
+        PreparedStatement { fragments }
+    }
+}
+
+impl PreparedStatement {
+    // <editor-fold desc="// 'Private' Methods ...">
+
+    /// Raise a `ProgrammingError` if the wrong number of parameters is supplied
+    fn _check_count(&self, parameters: &[&PyAny]) -> PyResult<()> {
+        if parameters.len() != self.placeholder_count() {
+            return Err(ProgrammingError::new_err(format!(
+                "Incorrect number of bindings supplied. The current statement uses {}, and there are {} supplied.",
+                self.placeholder_count(),
+                parameters.len(),
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Render each of the supplied parameters as a SQL literal
+    fn _literals(&self, parameters: &[&PyAny], encoding: &Encoding) -> PyResult<Vec<String>> {
+        self._check_count(parameters)?;
+
+        parameters
+            .iter()
+            .enumerate()
+            .map(|(idx, parameter)| {
+                value_from_py(parameter, encoding)
+                    .and_then(|value| sql_literal_from(&value))
+                    .map_err(|error| {
+                        let py = parameter.py();
+                        let message: String =
+                            format!("Error binding parameter {} - {}", idx + 1, error.pvalue(py));
+
+                        // Values that simply can't be stored keep their `DataError`
+                        iif!(
+                            error.is_instance::<DataError>(py),
+                            DataError::new_err(message),
+                            ProgrammingError::new_err(message)
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    // </editor-fold desc="// 'Private' Methods ...">
+
+    // <editor-fold desc="// Public Methods ...">
+
+    /// The number of `?` placeholders in the statement
+    pub fn placeholder_count(&self) -> usize {
+        self.fragments.len() - 1
+    }
+
+    /// Produce executable SQL by substituting each of the
+    /// statement's placeholders with the corresponding
+    /// (safely quoted) parameter value, decoding any
+    /// `bytes` parameters with the supplied encoding
+    pub fn bind(&self, parameters: &[&PyAny], encoding: &Encoding) -> PyResult<String> {
+        let mut sql: String = self.fragments[0].clone();
+
+        for (literal, fragment) in self
+            ._literals(parameters, encoding)?
+            .iter()
+            .zip(self.fragments[1..].iter())
+        {
+            sql.push_str(literal);
+            sql.push_str(fragment);
+        }
+
+        Ok(sql)
+    }
+
+    /// Parse the statement (once) so that it can be bound to any number of
+    /// parameter sets by `bind_parsed`, with an identifier standing in for
+    /// each of its placeholders. Returns `None` if the statement can't be
+    /// parsed that way, in which case it has to be bound with `bind` instead.
+    pub fn parse(&self) -> Option<Statement> {
+        let mut sql: String = self.fragments[0].clone();
+
+        for (idx, fragment) in self.fragments[1..].iter().enumerate() {
+            sql.push_str(&format!(" {}{}__ ", PLACEHOLDER_PREFIX, idx));
+            sql.push_str(fragment);
+        }
+
+        match parse(&sql).ok()?.as_slice() {
+            [statement] => translate(statement).ok(),
+            _ => None,
+        }
+    }
+
+    /// Bind a set of parameters to a copy of a statement parsed by `parse`.
+    /// Returns `None` if any of the statement's placeholders are somewhere
+    /// (e.g. in a function's arguments) that can't be bound in place.
+    pub fn bind_parsed(
+        &self,
+        statement: &Statement,
+        parameters: &[&PyAny],
+        encoding: &Encoding,
+    ) -> PyResult<Option<Statement>> {
+        let values: Vec<Expr> = self
+            ._literals(parameters, encoding)?
+            .iter()
+            .map(|literal| {
+                parse_expr(literal)
+                    .map_err(py_err_from_sql)
+                    .and_then(|expr| translate_expr(&expr).map_err(py_err_from_sql))
+            })
+            .collect::<PyResult<Vec<Expr>>>()?;
+
+        let mut statement: Statement = statement.clone();
+
+        Ok(iif!(
+            bind_statement(&mut statement, &values) == values.len(),
+            Some(statement),
+            None
+        ))
+    }
+
+    // </editor-fold desc="// Public Methods ...">
+}
+
+// </editor-fold desc="// PreparedStatement ...">
+
+// <editor-fold desc="// Helpers ...">
+
+/// Substitute the placeholders in a parsed statement with the
+/// supplied values, returning the number of substitutions made
+fn bind_statement(statement: &mut Statement, values: &[Expr]) -> usize {
+    match statement {
+        Statement::Query(query) => bind_query(query, values),
+        Statement::Insert { source, .. } => bind_query(source, values),
+        Statement::Update {
+            assignments,
+            selection,
+            ..
+        } => {
+            assignments
+                .iter_mut()
+                .map(|assignment| bind_expr(&mut assignment.value, values))
+                .sum::<usize>()
+                + selection
+                    .iter_mut()
+                    .map(|expr| bind_expr(expr, values))
+                    .sum::<usize>()
+        }
+        Statement::Delete { selection, .. } => selection
+            .iter_mut()
+            .map(|expr| bind_expr(expr, values))
+            .sum(),
+        _ => 0,
+    }
+}
+
+/// Substitute the placeholders in a (sub-)query
+fn bind_query(query: &mut Query, values: &[Expr]) -> usize {
+    let body: usize = match &mut query.body {
+        SetExpr::Values(rows) => rows
+            .0
+            .iter_mut()
+            .flatten()
+            .map(|expr| bind_expr(expr, values))
+            .sum(),
+        SetExpr::Select(select) => {
+            select
+                .projection
+                .iter_mut()
+                .map(|item| match item {
+                    SelectItem::Expr { expr, .. } => bind_expr(expr, values),
+                    _ => 0,
+                })
+                .sum::<usize>()
+                + select
+                    .from
+                    .joins
+                    .iter_mut()
+                    .map(|join| match &mut join.join_operator {
+                        JoinOperator::Inner(JoinConstraint::On(expr))
+                        | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => {
+                            bind_expr(expr, values)
+                        }
+                        _ => 0,
+                    })
+                    .sum::<usize>()
+                + select
+                    .selection
+                    .iter_mut()
+                    .chain(select.group_by.iter_mut())
+                    .chain(select.having.iter_mut())
+                    .chain(
+                        select
+                            .order_by
+                            .iter_mut()
+                            .map(|order_by| &mut order_by.expr),
+                    )
+                    .map(|expr| bind_expr(expr, values))
+                    .sum::<usize>()
+        }
+    };
+
+    body + query
+        .limit
+        .iter_mut()
+        .chain(query.offset.iter_mut())
+        .map(|expr| bind_expr(expr, values))
+        .sum::<usize>()
+}
+
+/// Substitute the placeholders in an expression (function
+/// and aggregate arguments are deliberately left alone)
+fn bind_expr(expr: &mut Expr, values: &[Expr]) -> usize {
+    match expr {
+        Expr::Identifier(name) => {
+            match name
+                .strip_prefix(PLACEHOLDER_PREFIX)
+                .and_then(|rest| rest.strip_suffix("__"))
+                .and_then(|idx| idx.parse::<usize>().ok())
+                .and_then(|idx| values.get(idx))
+            {
+                Some(value) => {
+                    *expr = value.clone();
+                    1
+                }
+                None => 0,
+            }
+        }
+        Expr::IsNull(inner)
+        | Expr::IsNotNull(inner)
+        | Expr::UnaryOp { expr: inner, .. }
+        | Expr::Cast { expr: inner, .. }
+        | Expr::Extract { expr: inner, .. }
+        | Expr::Nested(inner) => bind_expr(inner, values),
+        Expr::InList {
+            expr: inner, list, ..
+        } => {
+            bind_expr(inner, values)
+                + list
+                    .iter_mut()
+                    .map(|item| bind_expr(item, values))
+                    .sum::<usize>()
+        }
+        Expr::InSubquery {
+            expr: inner,
+            subquery,
+            ..
+        } => bind_expr(inner, values) + bind_query(subquery, values),
+        Expr::Between {
+            expr: inner,
+            low,
+            high,
+            ..
+        } => bind_expr(inner, values) + bind_expr(low, values) + bind_expr(high, values),
+        Expr::BinaryOp { left, right, .. } => bind_expr(left, values) + bind_expr(right, values),
+        Expr::Exists(query) | Expr::Subquery(query) => bind_query(query, values),
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            operand
+                .iter_mut()
+                .map(|expr| bind_expr(expr, values))
+                .sum::<usize>()
+                + when_then
+                    .iter_mut()
+                    .map(|(when, then)| bind_expr(when, values) + bind_expr(then, values))
+                    .sum::<usize>()
+                + else_result
+                    .iter_mut()
+                    .map(|expr| bind_expr(expr, values))
+                    .sum::<usize>()
+        }
+        _ => 0,
+    }
+}
+
+/// Quote a string as a SQL string literal
+fn quoted<T: AsRef<str>>(value: T) -> String {
+    format!("'{}'", value.as_ref().replace('\'', "''"))
+}
+
+/// Render a GlueSQL value as an equivalent SQL literal
+pub(crate) fn sql_literal_from(value: &Value) -> PyResult<String> {
+    Ok(match value {
+        Value::Null => "NULL".to_string(),
+        Value::Bool(val) => iif!(*val, "TRUE", "FALSE").to_string(),
+        Value::I8(val) => val.to_string(),
+        Value::I64(val) => val.to_string(),
+        Value::F64(val) if val.is_finite() => format!("{:?}", val),
+        Value::F64(val) => {
+            return Err(DataError::new_err(format!(
+                "non-finite float '{}' can't be stored",
+                val
+            )))
+        }
+        Value::Decimal(val) => val.to_string(),
+        Value::Str(val) => quoted(val),
+        Value::Date(val) => format!("DATE {}", quoted(val.to_string())),
+        Value::Time(val) => format!("TIME {}", quoted(val.to_string())),
+        Value::Timestamp(val) => format!("TIMESTAMP {}", quoted(val.to_string())),
+        other => {
+            return Err(ProgrammingError::new_err(format!(
+                "value '{:?}' can't be rendered as a literal",
+                other
+            )))
+        }
+    })
+}
+
+// </editor-fold desc="// Helpers ...">
+
+// <editor-fold desc="// Tests ...">
+
+#[cfg(test)]
+mod tests {
+    use gluesql::core::ast::{AstLiteral, Expr};
+    use gluesql::core::data::Value;
+    use pyo3_chrono::chrono::NaiveDate;
+
+    use super::{bind_statement, sql_literal_from, PreparedStatement};
+
+    #[test]
+    /// Test that `PreparedStatement` only splits on "real" placeholders
+    fn finds_placeholders() {
+        let statement = PreparedStatement::from("SELECT * FROM CUSTOMER WHERE NAME = ? AND ID > ?");
+
+        assert_eq!(statement.placeholder_count(), 2);
+        assert_eq!(
+            statement.fragments,
+            vec![
+                "SELECT * FROM CUSTOMER WHERE NAME = ".to_string(),
+                " AND ID > ".to_string(),
+                String::new(),
+            ]
+        );
+
+        let quoted = PreparedStatement::from("SELECT '?', 'it''s?', \"odd?\" FROM T WHERE A = ?");
+
+        assert_eq!(quoted.placeholder_count(), 1);
+
+        let commented = PreparedStatement::from("SELECT A -- why?\nFROM T WHERE B = ?");
+
+        assert_eq!(commented.placeholder_count(), 1);
+        assert_eq!(PreparedStatement::from("SELECT 1").placeholder_count(), 0);
+    }
+
+    #[test]
+    /// Test that parsed statements are bound in place
+    fn binds_parsed_statements() {
+        let values: Vec<Expr> = (1..4)
+            .map(|number| Expr::Literal(AstLiteral::Number(number.into())))
+            .collect();
+
+        let statement = PreparedStatement::from(
+            "UPDATE T SET A = ? WHERE B = ? AND C IN (SELECT X FROM Y WHERE Z = ?)",
+        );
+        let mut parsed = statement.parse().unwrap();
+
+        assert_eq!(bind_statement(&mut parsed, &values), 3);
+        assert!(!format!("{:?}", parsed).contains("__FERROFLEX_PARAMETER_"));
+
+        // Placeholders in a function's arguments can't be bound in place
+        let mut parsed = PreparedStatement::from("DELETE FROM T WHERE UPPER(A) = UPPER(?)")
+            .parse()
+            .unwrap();
+
+        assert_eq!(bind_statement(&mut parsed, &values), 0);
+    }
+
+    #[test]
+    /// Test that values are rendered as equivalent SQL literals
+    fn renders_literals() {
+        let timestamp = NaiveDate::from_ymd(2021, 12, 31).and_hms_milli(23, 59, 1, 500);
+
+        for (value, expected) in [
+            (Value::Null, "NULL"),
+            (Value::Bool(true), "TRUE"),
+            (Value::I64(-42), "-42"),
+            (Value::F64(2.0), "2.0"),
+            (Value::Decimal("10.250".parse().unwrap()), "10.250"),
+            (Value::Str("it's".to_string()), "'it''s'"),
+            (Value::Date(timestamp.date()), "DATE '2021-12-31'"),
+            (Value::Time(timestamp.time()), "TIME '23:59:01.500'"),
+            (
+                Value::Timestamp(timestamp),
+                "TIMESTAMP '2021-12-31 23:59:01.500'",
+            ),
+        ] {
+            assert_eq!(sql_literal_from(&value).unwrap(), expected);
+        }
+
+        assert!(sql_literal_from(&Value::F64(f64::NAN)).is_err());
+    }
+}
+
+// </editor-fold desc="// Tests ...">
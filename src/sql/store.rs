@@ -2,13 +2,15 @@
 
 // Third-Party Imports
 use async_trait::async_trait;
+use caseless::compatibility_caseless_match_str as cl_eq;
 use gluesql::core::data::{Row, Schema};
 use gluesql::core::result::MutResult as MutSqlResult;
 use gluesql::core::result::Result as SqlResult;
 use gluesql::core::store::{GStore, GStoreMut, RowIter, Store, StoreMut};
 
 // Crate-Level Imports
-use crate::structs::DataFlexDB;
+use crate::sql::ensure_writable;
+use crate::structs::{DataFlexDB, RelationshipGraph, FOREIGN_KEYS_TABLE};
 use crate::AttrIndexSliceOrItem;
 
 #[async_trait(?Send)]
@@ -18,12 +20,26 @@ impl Store<usize> for DataFlexDB {
             return Ok(Some(table.schema()));
         }
 
+        // The db's relationships are exposed as a virtual (read-only)
+        // table of foreign keys, since GlueSQL's schemas can't hold them
+        if cl_eq(table_name, FOREIGN_KEYS_TABLE) {
+            return Ok(Some(RelationshipGraph::schema()));
+        }
+
         Ok(None)
     }
 
     async fn scan_data(&self, table_name: &str) -> SqlResult<RowIter<usize>> {
         if let Some(table) = self.get(AttrIndexSliceOrItem::Name(table_name)) {
-            return Ok(Box::new(table.clone().iter()));
+            return Ok(Box::new(
+                table.clone().iter().with_interrupt(self.interrupt_flag()),
+            ));
+        }
+
+        if cl_eq(table_name, FOREIGN_KEYS_TABLE) {
+            return Ok(Box::new(
+                self.relationships().rows().into_iter().enumerate().map(Ok),
+            ));
         }
 
         Err(gluesql::core::data::TableError::Unreachable.into())
@@ -48,22 +64,32 @@ impl GStore<usize> for DataFlexDB {}
 #[async_trait(?Send)]
 impl StoreMut<Row> for DataFlexDB {
     async fn insert_schema(self, schema: &Schema) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 
     async fn delete_schema(self, table_name: &str) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 
     async fn insert_data(self, table_name: &str, rows: Vec<Row>) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 
     async fn update_data(self, table_name: &str, rows: Vec<(Row, Row)>) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 
     async fn delete_data(self, table_name: &str, keys: Vec<Row>) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 }
@@ -72,14 +98,20 @@ impl StoreMut<Row> for DataFlexDB {
 #[async_trait(?Send)]
 impl StoreMut<usize> for DataFlexDB {
     async fn insert_schema(self, schema: &Schema) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 
     async fn delete_schema(self, table_name: &str) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 
     async fn insert_data(self, table_name: &str, rows: Vec<Row>) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 
@@ -88,10 +120,14 @@ impl StoreMut<usize> for DataFlexDB {
         table_name: &str,
         rows: Vec<(usize, Row)>,
     ) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 
     async fn delete_data(self, table_name: &str, keys: Vec<usize>) -> MutSqlResult<Self, ()> {
+        let (_db, ()) = ensure_writable(self)?;
+
         todo!()
     }
 }
@@ -1,25 +1,39 @@
-// // Implementation of GlueSQL's optional `Transaction` trait for DataFlex table files
-//
-// // Third-Party Imports
-// use async_trait::async_trait;
-// use gluesql::core::result::MutResult as MutSqlResult;
-// use gluesql::core::store::Transaction;
-//
-// // Crate-Level Imports
-// use crate::structs::database::DataFlexDB;
-//
-// #[allow(unused_variables)]
-// #[async_trait]
-// impl Transaction for DataFlexDB {
-//     async fn begin(self, autocommit: bool) -> MutSqlResult<Self, bool> {
-//         todo!()
-//     }
-//
-//     async fn rollback(self) -> MutSqlResult<Self, ()> {
-//         todo!()
-//     }
-//
-//     async fn commit(self) -> MutSqlResult<Self, ()> {
-//         todo!()
-//     }
-// }
+// Implementation of GlueSQL's optional `Transaction` trait for DataFlex table files
+
+// Third-Party Imports
+use async_trait::async_trait;
+use gluesql::core::result::MutResult as MutSqlResult;
+use gluesql::core::store::Transaction;
+
+// Crate-Level Imports
+use crate::sql::StorageError;
+use crate::structs::database::DataFlexDB;
+
+// DataFlex table files have no notion of transactions, so only the
+// implicit "autocommit" transaction GlueSQL wraps around each statement
+// is supported. Committing or rolling that back is a no-op, since every
+// change has already been written (or, in read-only mode, never can be).
+#[async_trait(?Send)]
+impl Transaction for DataFlexDB {
+    async fn begin(self, autocommit: bool) -> MutSqlResult<Self, bool> {
+        if autocommit {
+            return Ok((self, true));
+        }
+
+        let error = if self.options.read_only {
+            StorageError::ReadOnly
+        } else {
+            StorageError::Unsupported("BEGIN TRANSACTION".to_string())
+        };
+
+        Err((self, error.into()))
+    }
+
+    async fn rollback(self) -> MutSqlResult<Self, ()> {
+        Ok((self, ()))
+    }
+
+    async fn commit(self) -> MutSqlResult<Self, ()> {
+        Ok((self, ()))
+    }
+}
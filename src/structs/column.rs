@@ -8,35 +8,43 @@ use std::fmt;
 use byteorder::{ByteOrder, LittleEndian};
 use gluesql::core::ast::ColumnDef;
 use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
+use pyo3::prelude::*;
 use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 // Crate-Level Imports
-use crate::enums::DataType;
+use crate::enums::{DataType, DiagnosticKind};
 use crate::iif;
+use crate::structs::Diagnostic;
 
 // <editor-fold desc="// Column ...">
 
 #[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
 /// A structured representation of a column's definition
 /// in the header of a DataFlex table file
 pub struct Column {
+    #[pyo3(get)]
     /// The column's human-readable name
     pub name: String,
+    #[pyo3(get)]
     /// The number of bytes between the
     /// first byte of a record and the first
     /// byte of the column's data
     pub offset: u64,
+    #[pyo3(get)]
     /// The numerical "id" of the column's
     /// "primary" index (as defined in the
     /// header of the table to which the
     /// column belongs)
     pub main_index: Option<u64>,
+    #[pyo3(get)]
     /// The number of digits to the
     /// right of the decimal (if the
     /// column represents a numerical
     /// data type)
     pub decimal_points: u64,
+    #[pyo3(get)]
     /// The total number of bytes occupied
     /// by the column's data with respect
     /// to a single row in a given table
@@ -44,6 +52,7 @@ pub struct Column {
     /// The "type" of data stored in /
     /// represented by the column
     pub data_type: DataType,
+    #[pyo3(get)]
     /// The numerical "id" of the table
     /// holding the "remote" column to
     /// which the column is a foreign key
@@ -51,6 +60,7 @@ pub struct Column {
     /// associated with the table to which
     /// the column belongs)
     pub related_file: Option<u64>,
+    #[pyo3(get)]
     /// The numerical "id" of the "remote"
     /// column on another table to which
     /// the column is a foreign key (as
@@ -199,29 +209,58 @@ impl Column {
         })
     }
 
-    pub fn table_from_bytes(data: &[u8], names: Option<Vec<String>>) -> PyResult<Vec<Column>> {
-        let chunks = data.chunks_exact(8);
-
-        if let Some(n) = names {
-            return Ok(chunks
-                .enumerate()
-                .filter(|pair| pair.0 < n.len())
-                .map(|pair| Column::from_bytes(pair.1, Some(&n[min(pair.0, n.len() - 1)])))
-                .filter(PyResult::is_ok)
-                .map(PyResult::unwrap)
-                .collect::<Vec<Column>>());
+    /// Parse the column definitions in `data`, recording any that can't
+    /// be parsed in `diagnostics` (with offsets relative to `data`)
+    pub fn table_from_bytes(
+        data: &[u8],
+        names: Option<Vec<String>>,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> PyResult<Vec<Column>> {
+        let mut columns: Vec<Column> = Vec::new();
+
+        for (idx, chunk) in data.chunks_exact(8).enumerate() {
+            let name: Option<&str> = match &names {
+                Some(n) if idx >= n.len() => break,
+                Some(n) => Some(&n[min(idx, n.len() - 1)]),
+                None => None,
+            };
+
+            match Column::from_bytes(chunk, name) {
+                Ok(column) => columns.push(column),
+                Err(error) => diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Column,
+                    "",
+                    (idx * 8) as u64,
+                    error,
+                )),
+            }
         }
 
-        Ok(chunks
-            .map(|val| Column::from_bytes(val, None))
-            .filter(PyResult::is_ok)
-            .map(PyResult::unwrap)
-            .collect::<Vec<Column>>())
+        Ok(columns)
     }
 
     // </editor-fold desc="// Public Methods ...">
 }
 
+#[pymethods]
+impl Column {
+    #[getter]
+    /// The "type" of data stored in / represented by the column
+    fn get_data_type(&self) -> String {
+        self.data_type.to_string()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
 // </editor-fold desc="// Column ...">
 
 // <editor-fold desc="// Tests ...">
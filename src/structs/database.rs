@@ -1,44 +1,134 @@
 // A structured representation of a collection of DataFlex table files
 
 // Standard Library Imports
-use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::fmt;
 use std::iter::IntoIterator;
 use std::ops::Index as Indexable;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // Third-Party Imports
+use caseless::compatibility_caseless_match_str as cl_eq;
 use gluesql::core::data::Schema;
 use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
 use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 // Crate-Level Imports
-use crate::structs::{DataFlexTable, FileList};
+use crate::enums::{DiagnosticKind, Encoding, Version};
+use crate::exceptions::NotSupportedError;
+use crate::options::OpenOptions;
+use crate::structs::{
+    Column, DataFlexTable, DeleteImpact, Diagnostic, FileList, FileListEntry, IntegrityReport,
+    JoinPath, Relationship, RelationshipGraph, TableDefinition, TagFile,
+};
 use crate::utils::{path_from_string, string_from_path};
-use crate::{iif, AttrIndexSliceOrItem};
+use crate::{iif, AttrIndexSliceOrItem, InterruptFlag};
+
+// <editor-fold desc="// FailedTable ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+/// A table listed in a db's filelist that couldn't be opened
+pub struct FailedTable {
+    #[pyo3(get)]
+    /// The table's DataFlex file number
+    pub file_number: usize,
+    #[pyo3(get)]
+    /// The table's on-disk file name
+    pub root_name: String,
+    #[pyo3(get)]
+    /// The path the table was expected to be found at
+    pub path: String,
+    #[pyo3(get)]
+    /// Why the table couldn't be opened
+    pub reason: String,
+}
+
+unsafe impl Send for FailedTable {}
+
+impl fmt::Display for FailedTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "FailedTable<number: {} | root_name: {} | reason: {}>",
+            self.file_number, self.root_name, self.reason
+        )
+    }
+}
+
+impl FailedTable {
+    /// Describe the failure as a (table) diagnostic
+    pub fn diagnostic(&self) -> Diagnostic {
+        Diagnostic::new(DiagnosticKind::Table, &self.path, 0, &self.reason)
+    }
+}
+
+#[pymethods]
+impl FailedTable {
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+// </editor-fold desc="// FailedTable ...">
 
 // <editor-fold desc="// DataFlexDB ...">
 
 #[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+#[pyo3(text_signature = "(db_path: str)")]
 /// A structured representation of a collection of DataFlex table files
 pub struct DataFlexDB {
     /// The db's on-disk path
     pub db_path: PathBuf,
+    #[pyo3(get)]
     /// The db's filelist
     pub filelist: FileList,
-    /// The db's filelist
+    #[pyo3(get)]
+    /// The db's (successfully opened) tables
     pub tables: Vec<DataFlexTable>,
+    #[pyo3(get)]
+    /// The tables in the db's filelist that couldn't be opened
+    pub failed_tables: Vec<FailedTable>,
+    /// The options the db was opened with
+    pub options: OpenOptions,
+    /// The flag used to cancel the db's running scans
+    #[serde(skip)]
+    pub(crate) interrupt: InterruptFlag,
 }
 
 unsafe impl Send for DataFlexDB {}
 
-#[allow(unused_variables)]
-impl<T: Into<i64>> Indexable<T> for DataFlexDB {
-    type Output = PyResult<DataFlexTable>;
+impl Indexable<usize> for DataFlexDB {
+    type Output = DataFlexTable;
 
-    fn index(&self, index: T) -> &'static Self::Output {
-        todo!()
+    fn index(&self, file_number: usize) -> &Self::Output {
+        match self.table_by_number(file_number) {
+            Some(table) => table,
+            None => panic!(
+                "no table is loaded for file number {} in '{}'",
+                file_number,
+                self.db_path.display()
+            ),
+        }
+    }
+}
+
+impl Indexable<&str> for DataFlexDB {
+    type Output = DataFlexTable;
+
+    fn index(&self, name: &str) -> &Self::Output {
+        match self.table_by_name(name) {
+            Some(table) => table,
+            None => panic!(
+                "no table named '{}' is loaded in '{}'",
+                name,
+                self.db_path.display()
+            ),
+        }
     }
 }
 
@@ -51,10 +141,15 @@ impl IntoIterator for DataFlexDB {
     }
 }
 
-#[allow(unused_variables)]
 impl fmt::Display for DataFlexDB {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        todo!()
+        write!(
+            f,
+            "DataFlexDB<path: {} | tables: {}>\n{}",
+            self.db_path.display(),
+            self.tables.len(),
+            self._as_pretty_table()
+        )
     }
 }
 
@@ -82,10 +177,153 @@ impl DataFlexDB {
         table.to_string()
     }
 
+    /// Find the loaded table whose file is named by a filelist entry
+    fn _table_for_entry(&self, entry: &FileListEntry) -> Option<&DataFlexTable> {
+        let root_name: &str = root_file_name(entry);
+
+        self.tables.iter().find(|table| {
+            Path::new(&table.header.filepath)
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| cl_eq(stem, root_name))
+        })
+    }
+
     // </editor-fold desc="// 'Private' Methods ...">
 
     // <editor-fold desc="// Public Methods ...">
 
+    /// Get the table with the supplied DataFlex file number
+    /// (i.e. its position in the db's `filelist.cfg`)
+    pub fn table_by_number(&self, file_number: usize) -> Option<&DataFlexTable> {
+        self.filelist
+            .entry(file_number)
+            .and_then(|entry| self._table_for_entry(entry))
+    }
+
+    /// Get the DataFlex file number of the table with the supplied
+    /// root name or DataFlex (logical) name, ignoring case
+    pub fn file_number_of(&self, name: &str) -> Option<usize> {
+        let name_query = AttrIndexSliceOrItem::Name(name);
+
+        self.filelist
+            .iter()
+            .find(|entry| entry.file_number > 0 && entry.is(&name_query))
+            .map(|entry| entry.file_number)
+    }
+
+    /// Get the table with the supplied root name or
+    /// DataFlex (logical) name, ignoring case
+    pub fn table_by_name(&self, name: &str) -> Option<&DataFlexTable> {
+        let name_query = AttrIndexSliceOrItem::Name(name);
+
+        self.filelist
+            .iter()
+            .filter(|entry| entry.file_number > 0 && entry.is(&name_query))
+            .find_map(|entry| self._table_for_entry(entry))
+            .or_else(|| self.tables.iter().find(|table| *table == name))
+    }
+
+    /// Resolve every column's `related_file` / `related_field` through
+    /// the db's filelist into a graph of parent and child tables. Relations
+    /// that point at a file number with no loaded table are left out, and
+    /// each child's relations to the same parent are kept together (in
+    /// column order) as the segments of a single key.
+    pub fn relationships(&self) -> RelationshipGraph {
+        let mut graph = RelationshipGraph::default();
+
+        for child_entry in self.filelist.iter().filter(|entry| entry.file_number > 0) {
+            let child = match self._table_for_entry(child_entry) {
+                Some(table) => table,
+                None => continue,
+            };
+
+            graph.tables.push(root_file_name(child_entry).to_string());
+
+            let mut relations: Vec<Relationship> = Vec::new();
+
+            for column in child.header.columns.iter() {
+                let parent_file: usize = match column.related_file {
+                    Some(file_number) => file_number as usize,
+                    None => continue,
+                };
+
+                let (parent_entry, parent) = match self
+                    .filelist
+                    .entry(parent_file)
+                    .and_then(|entry| Some((entry, self._table_for_entry(entry)?)))
+                {
+                    Some(pair) => pair,
+                    None => continue,
+                };
+
+                // DataFlex field numbers start at 1 (field 0 being
+                // the implicit record number)
+                let parent_column: String = match column.related_field {
+                    None | Some(0) => "RECNUM".to_string(),
+                    Some(field) => match parent.header.columns.get(field as usize - 1) {
+                        Some(related) => related.name.clone(),
+                        None => format!("Column{}", field),
+                    },
+                };
+
+                relations.push(Relationship {
+                    child_file: child_entry.file_number,
+                    child_table: root_file_name(child_entry).to_string(),
+                    child_column: column.name.clone(),
+                    parent_file,
+                    parent_table: root_file_name(parent_entry).to_string(),
+                    parent_column,
+                });
+            }
+
+            // A stable sort keeps each key's segments in column order
+            relations.sort_by_key(|relation| relation.parent_file);
+            graph.relationships.extend(relations);
+        }
+
+        graph
+    }
+
+    /// Find the shortest chain of relations linking two tables and the
+    /// SQL needed to join them (similar to DataFlex's `RELATE`)
+    pub fn join(&self, from_table: &str, to_table: &str) -> PyResult<JoinPath> {
+        let file_number = |name: &str| -> PyResult<usize> {
+            self.file_number_of(name)
+                .ok_or_else(|| PyKeyError::new_err(name.to_string()))
+        };
+
+        let (from_file, to_file) = (file_number(from_table)?, file_number(to_table)?);
+
+        if from_file == to_file {
+            return Err(PyValueError::new_err(format!(
+                "can't join '{}' to itself",
+                from_table
+            )));
+        }
+
+        self.relationships()
+            .join_path(from_file, to_file)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "'{}' and '{}' aren't related",
+                    from_table, to_table
+                ))
+            })
+    }
+
+    /// Find every child record whose related value matches no parent
+    /// record, across every relationship in the db
+    pub fn check_integrity(&self) -> PyResult<IntegrityReport> {
+        IntegrityReport::for_db(self)
+    }
+
+    /// Find the child records that deleting `record_number`
+    /// from `table` would leave orphaned
+    pub fn delete_impact(&self, table: &str, record_number: u64) -> PyResult<Vec<DeleteImpact>> {
+        DeleteImpact::for_record(self, table, record_number)
+    }
+
     pub fn len(&self) -> usize {
         self.filelist.len()
     }
@@ -98,23 +336,23 @@ impl DataFlexDB {
         self.into_iter()
     }
 
-    pub fn get(
+    pub fn get<'fl>(
         &'fl self,
         table: AttrIndexSliceOrItem<DataFlexTable>,
     ) -> Option<&'fl DataFlexTable> {
+        // Indexes are DataFlex file numbers (not positions in `tables`)
+        // and names may be either root names or DataFlex names
         match table {
-            AttrIndexSliceOrItem::Index(idx) => {
-                let idx: isize = iif!(idx > 0, idx, self.tables.len() as isize + idx);
-                if idx < 0 {
-                    return None;
-                }
-
-                Some(&self.tables[idx as usize])
+            AttrIndexSliceOrItem::Index(file_number) => {
+                iif!(
+                    file_number < 1,
+                    None,
+                    self.table_by_number(file_number as usize)
+                )
             }
-            AttrIndexSliceOrItem::Name(name) => {
-                self.tables.iter().filter(|tbl| *tbl == name).next()
-            }
-            _ => None,
+            AttrIndexSliceOrItem::Name(name) => self.table_by_name(name),
+            AttrIndexSliceOrItem::Item(item) => self.tables.iter().find(|table| **table == item),
+            AttrIndexSliceOrItem::Slice(_) => None,
         }
     }
 
@@ -122,25 +360,125 @@ impl DataFlexDB {
         self.get(table).is_some()
     }
 
+    /// Find the definition of the column named `name`, searching the
+    /// tables named in `tables` (in order) or every table if no table
+    /// names are supplied
+    pub fn find_column<T: AsRef<str>>(&self, tables: &[T], name: &str) -> Option<&Column> {
+        let candidates: Vec<&DataFlexTable> = if tables.is_empty() {
+            self.tables.iter().collect()
+        } else {
+            tables
+                .iter()
+                .filter_map(|table| self.get(AttrIndexSliceOrItem::Name(table.as_ref())))
+                .collect()
+        };
+
+        candidates
+            .into_iter()
+            .flat_map(|table| table.header.columns.iter())
+            .find(|column| cl_eq(&column.name, name))
+    }
+
+    /// Raise a `NotSupportedError` if the db was opened read-only
+    pub fn ensure_writable(&self) -> PyResult<()> {
+        if self.options.read_only {
+            return Err(NotSupportedError::new_err(
+                "attempt to write a read-only database",
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn interrupt_flag(&self) -> InterruptFlag {
+        self.interrupt.clone()
+    }
+
     pub fn from_path<P: AsRef<str>>(db_path: P) -> PyResult<DataFlexDB> {
-        let filelist: FileList = FileList::from_path(db_path.borrow().as_ref())?;
+        DataFlexDB::open(db_path, OpenOptions::default())
+    }
+
+    pub fn open<P: AsRef<str>>(db_path: P, options: OpenOptions) -> PyResult<DataFlexDB> {
+        let filelist_path: String = db_path.as_ref().to_string();
+        let mut filelist: FileList = FileList::from_versioned_path(
+            &filelist_path,
+            options.filelist_version.clone(),
+            options.encoding.clone(),
+        )?;
         let db_path: PathBuf = path_from_string(db_path.as_ref(), Some(true));
 
-        let tables: Vec<DataFlexTable> = filelist
-            .borrow()
-            .iter()
-            .map(|file| db_path.join(format!("{}.dat", &file.root_name)))
-            .filter(|path| path.exists() && path.is_file())
-            .map(|path| DataFlexTable::from_path(string_from_path(path.as_path(), Some(false))))
-            .filter(PyResult::is_ok)
-            .map(PyResult::unwrap)
-            .collect();
-
-        Ok(DataFlexDB {
+        let mut tables: Vec<DataFlexTable> = Vec::new();
+        let mut failed_tables: Vec<FailedTable> = Vec::new();
+
+        for entry in filelist.iter().filter(|entry| entry.file_number > 0) {
+            let path: PathBuf = db_path.join(format!("{}.dat", entry.root_name));
+            let path_str: String = string_from_path(path.as_path(), Some(false));
+
+            let failure = |reason: String| FailedTable {
+                file_number: entry.file_number,
+                root_name: entry.root_name.clone(),
+                path: path_str.clone(),
+                reason,
+            };
+
+            if !(path.exists() && path.is_file()) {
+                failed_tables.push(failure("table file not found".to_string()));
+                continue;
+            }
+
+            match DataFlexTable::open(&path_str, &options) {
+                Ok(table) => tables.push(table),
+                Err(error) => failed_tables.push(failure(error.to_string())),
+            }
+        }
+
+        // Filelists of unknown versions are read using the 3.0 layout,
+        // which finds the same root names (and so the same tables) as
+        // the 2.3b layout. Once the tables have given away the db's
+        // version, the filelist is read again using that version's layout.
+        if filelist.version == Version::Unknown {
+            let version: Version = detect_version(&tables);
+
+            if version != Version::Unknown {
+                filelist = FileList::from_versioned_path(
+                    &filelist_path,
+                    version,
+                    options.encoding.clone(),
+                )?;
+            }
+        }
+
+        let db = DataFlexDB {
             db_path,
             filelist,
             tables,
-        })
+            failed_tables,
+            options,
+            interrupt: InterruptFlag::default(),
+        };
+
+        if db.options.strict {
+            Diagnostic::ensure_none(&db.diagnostics())?;
+        }
+
+        Ok(db)
+    }
+
+    /// Every table, filelist slot, index, and column that was skipped
+    /// while the db was being loaded (as recorded by the filelist, each
+    /// table's header, and the db's failed tables)
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.filelist
+            .diagnostics
+            .iter()
+            .chain(
+                self.tables
+                    .iter()
+                    .flat_map(|table| table.header.diagnostics.iter()),
+            )
+            .cloned()
+            .chain(self.failed_tables.iter().map(FailedTable::diagnostic))
+            .collect()
     }
 
     pub fn schema(&self) -> Vec<Schema> {
@@ -152,36 +490,759 @@ impl DataFlexDB {
 
     #[allow(unused_variables)]
     pub fn create_table(&self) -> PyResult<()> {
+        self.ensure_writable()?;
+
         todo!()
     }
 
     #[allow(unused_variables)]
     pub fn alter_table(&self) -> PyResult<()> {
+        self.ensure_writable()?;
+
         todo!()
     }
 
     #[allow(unused_variables)]
     pub fn drop_table(&self) -> PyResult<()> {
+        self.ensure_writable()?;
+
         todo!()
     }
 
+    /// Replace the db's filelist (if a new one is supplied), then
+    /// write it to the db's `filelist.cfg` file
+    pub fn save_filelist(&mut self, filelist: Option<FileList>) -> PyResult<()> {
+        self.ensure_writable()?;
+
+        if let Some(filelist) = filelist {
+            self.filelist = filelist;
+        }
+
+        self.filelist
+            .save(string_from_path(&self.db_path, Some(false)))
+    }
+
+    /// Write a tag file for every table named in `names` (by root name or
+    /// DataFlex name, ignoring case) that was loaded without one (or for
+    /// every named table if `overwrite` is set), renaming the tables'
+    /// columns to match. Returns the paths of the tag files written.
+    pub fn regenerate_tags(
+        &mut self,
+        names: &HashMap<String, Vec<String>>,
+        overwrite: bool,
+    ) -> PyResult<Vec<String>> {
+        self.ensure_writable()?;
+
+        let mut updates: Vec<(usize, TagFile)> = Vec::new();
+
+        // Check every table's names before anything is written
+        for (name, columns) in names.iter() {
+            let table: &DataFlexTable = self
+                .table_by_name(name)
+                .ok_or_else(|| PyKeyError::new_err(name.to_string()))?;
+
+            if table.header.tag_path.is_some() && !overwrite {
+                continue;
+            }
+
+            if columns.len() != table.header.columns.len() {
+                return Err(PyValueError::new_err(format!(
+                    "'{}' has {} columns but {} names were supplied",
+                    name,
+                    table.header.columns.len(),
+                    columns.len()
+                )));
+            }
+
+            let tags = TagFile::new(
+                companion_path(&table.header.filepath, "tag"),
+                columns.clone(),
+            );
+
+            tags.to_bytes()?;
+
+            match self
+                .tables
+                .iter()
+                .position(|other| std::ptr::eq(other, table))
+            {
+                Some(position) => updates.push((position, tags)),
+                None => continue,
+            }
+        }
+
+        updates.sort_by_key(|(position, _)| *position);
+
+        let mut written: Vec<String> = Vec::new();
+
+        for (position, tags) in updates {
+            tags.save()?;
+
+            let header = &mut self.tables[position].header;
+
+            for (column, name) in header.columns.iter_mut().zip(tags.tags.iter()) {
+                column.name = name.clone();
+            }
+
+            header.tag_path = Some(tags.filepath.clone());
+            written.push(tags.filepath);
+        }
+
+        Ok(written)
+    }
+
+    /// Write tag files from the column names in the supplied `definitions`
+    /// (or, if none are supplied, from each table's own `.FD` / `.DEF` file)
+    /// the way `regenerate_tags` does. Each definition is matched to its
+    /// table by file number, then by DataFlex name, then by root name.
+    pub fn regenerate_tags_from_definitions(
+        &mut self,
+        definitions: Option<&[TableDefinition]>,
+        overwrite: bool,
+    ) -> PyResult<Vec<String>> {
+        let mut names: HashMap<String, Vec<String>> = HashMap::new();
+
+        match definitions {
+            Some(definitions) => {
+                for definition in definitions {
+                    let name: String = match definition
+                        .file_number
+                        .and_then(|file_number| self.filelist.entry(file_number as usize))
+                    {
+                        Some(entry) => root_file_name(entry).to_string(),
+                        None => definition
+                            .dataflex_name
+                            .clone()
+                            .unwrap_or_else(|| definition.root_name.clone()),
+                    };
+
+                    names.insert(name, definition.column_names());
+                }
+            }
+            None => {
+                for entry in self.filelist.iter() {
+                    let path = match self
+                        ._table_for_entry(entry)
+                        .and_then(|table| table.header.definition_path.as_ref())
+                    {
+                        Some(path) => path,
+                        None => continue,
+                    };
+
+                    names.insert(
+                        root_file_name(entry).to_string(),
+                        TableDefinition::from_path(
+                            path,
+                            &self.options.encoding_for(root_file_name(entry)),
+                        )?
+                        .column_names(),
+                    );
+                }
+            }
+        }
+
+        self.regenerate_tags(&names, overwrite)
+    }
+
+    /// Describe the table with the supplied root name or DataFlex
+    /// name (ignoring case) the way its `.FD` / `.DEF` files would
+    pub fn table_definition(&self, name: &str) -> PyResult<TableDefinition> {
+        let (entry, table) = match self.file_number_of(name).and_then(|file_number| {
+            Some((
+                self.filelist.entry(file_number)?,
+                self.table_by_number(file_number)?,
+            ))
+        }) {
+            Some(pair) => pair,
+            None => return Err(PyKeyError::new_err(name.to_string())),
+        };
+
+        Ok(TableDefinition {
+            file_number: Some(entry.file_number as u64),
+            root_name: root_file_name(entry).to_string(),
+            dataflex_name: Some(dataflex_file_name(entry).to_string()),
+            description: entry.description.clone(),
+            ..TableDefinition::from_header(&table.header)
+        })
+    }
+
+    /// Write `.FD` and `.DEF` files for the table with the supplied root
+    /// name or DataFlex name into `directory` (or the table's own
+    /// directory), describing related columns by their parent's names.
+    /// Returns the paths of the files written.
+    pub fn write_definitions(&self, name: &str, directory: Option<&str>) -> PyResult<Vec<String>> {
+        if directory.is_none() {
+            self.ensure_writable()?;
+        }
+
+        let definition: TableDefinition = self.table_definition(name)?;
+        let table_path: &str = match definition
+            .file_number
+            .and_then(|file_number| self.table_by_number(file_number as usize))
+        {
+            Some(table) => &table.header.filepath,
+            None => return Err(PyKeyError::new_err(name.to_string())),
+        };
+
+        let related_name = |file: u64, field: u64| -> Option<String> {
+            let entry: &FileListEntry = self.filelist.entry(file as usize)?;
+            let column: String = match field {
+                0 => "RECNUM".to_string(),
+                field => self
+                    ._table_for_entry(entry)?
+                    .header
+                    .columns
+                    .get(field as usize - 1)?
+                    .name
+                    .clone(),
+            };
+
+            Some(format!("{}.{}", dataflex_file_name(entry), column))
+        };
+
+        let encoding: Encoding = self.options.encoding_for(table_path);
+        let mut written: Vec<String> = Vec::new();
+
+        for extension in ["fd", "def"] {
+            let mut path: String = companion_path(table_path, extension);
+
+            if let Some(directory) = directory {
+                if let Some(file_name) = Path::new(&path).file_name() {
+                    path = string_from_path(&Path::new(directory).join(file_name), Some(false));
+                }
+            }
+
+            definition.save_with(&path, &encoding, related_name)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+
     // </editor-fold desc="// Public Methods ...">
 }
 
+#[pymethods]
+impl DataFlexDB {
+    #[new]
+    /// Open the DataFlex "database" at `db_path`
+    fn py_new(db_path: &str) -> PyResult<DataFlexDB> {
+        DataFlexDB::from_path(db_path)
+    }
+
+    #[getter]
+    /// The db's on-disk path
+    fn get_db_path(&self) -> String {
+        string_from_path(&self.db_path, Some(false))
+    }
+
+    #[getter]
+    /// Every table, filelist slot, index, and column that
+    /// was skipped while the db was being loaded
+    fn get_diagnostics(&self) -> Vec<Diagnostic> {
+        self.diagnostics()
+    }
+
+    #[pyo3(
+        name = "save_filelist",
+        text_signature = "($self, filelist: Optional[FileList] = None)"
+    )]
+    /// Replace the db's filelist (if a new one is supplied),
+    /// then write it to the db's `filelist.cfg` file
+    fn py_save_filelist(&mut self, filelist: Option<FileList>) -> PyResult<()> {
+        self.save_filelist(filelist)
+    }
+
+    #[pyo3(
+        name = "regenerate_tags",
+        text_signature = "($self, names: Dict[str, List[str]], overwrite: bool = False) -> List[str]"
+    )]
+    /// Write tag files for the tables named in `names` that were
+    /// loaded without one, returning the paths written
+    fn py_regenerate_tags(
+        &mut self,
+        names: HashMap<String, Vec<String>>,
+        overwrite: Option<bool>,
+    ) -> PyResult<Vec<String>> {
+        self.regenerate_tags(&names, overwrite.unwrap_or_default())
+    }
+
+    #[pyo3(
+        name = "regenerate_tags_from_definitions",
+        text_signature = "($self, definitions: Optional[List[TableDefinition]] = None, overwrite: bool = False) -> List[str]"
+    )]
+    /// Write tag files from table definitions (or each table's own
+    /// `.FD` / `.DEF` file), returning the paths written
+    fn py_regenerate_tags_from_definitions(
+        &mut self,
+        definitions: Option<Vec<TableDefinition>>,
+        overwrite: Option<bool>,
+    ) -> PyResult<Vec<String>> {
+        self.regenerate_tags_from_definitions(definitions.as_deref(), overwrite.unwrap_or_default())
+    }
+
+    #[pyo3(
+        name = "table_definition",
+        text_signature = "($self, name: str) -> TableDefinition"
+    )]
+    /// Describe a table the way its `.FD` / `.DEF` files would
+    fn py_table_definition(&self, name: &str) -> PyResult<TableDefinition> {
+        self.table_definition(name)
+    }
+
+    #[pyo3(
+        name = "write_definitions",
+        text_signature = "($self, name: str, directory: Optional[str] = None) -> List[str]"
+    )]
+    /// Write `.FD` and `.DEF` files for a table into `directory`
+    /// (or the table's own directory), returning the paths written
+    fn py_write_definitions(&self, name: &str, directory: Option<&str>) -> PyResult<Vec<String>> {
+        self.write_definitions(name, directory)
+    }
+
+    #[pyo3(
+        name = "relationships",
+        text_signature = "($self) -> RelationshipGraph"
+    )]
+    /// The graph of parent / child relationships between the db's tables
+    fn py_relationships(&self) -> RelationshipGraph {
+        self.relationships()
+    }
+
+    #[pyo3(
+        name = "join",
+        text_signature = "($self, from_table: str, to_table: str) -> JoinPath"
+    )]
+    /// Find the shortest chain of relations linking two tables
+    /// and the SQL needed to join them
+    fn py_join(&self, from_table: &str, to_table: &str) -> PyResult<JoinPath> {
+        self.join(from_table, to_table)
+    }
+
+    #[pyo3(
+        name = "check_integrity",
+        text_signature = "($self) -> IntegrityReport"
+    )]
+    /// Find every child record whose related value matches no parent record
+    fn py_check_integrity(&self) -> PyResult<IntegrityReport> {
+        self.check_integrity()
+    }
+
+    #[pyo3(
+        name = "delete_impact",
+        text_signature = "($self, table: str, record_number: int) -> List[DeleteImpact]"
+    )]
+    /// Find the child records that deleting `record_number`
+    /// from `table` would leave orphaned
+    fn py_delete_impact(&self, table: &str, record_number: u64) -> PyResult<Vec<DeleteImpact>> {
+        self.delete_impact(table, record_number)
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Get a table by DataFlex file number or by name
+    fn __getitem__(&self, key: AttrIndexSliceOrItem<DataFlexTable>) -> PyResult<DataFlexTable> {
+        let table: Option<&DataFlexTable> = match &key {
+            AttrIndexSliceOrItem::Index(file_number) if *file_number >= 0 => {
+                self.table_by_number(*file_number as usize)
+            }
+            AttrIndexSliceOrItem::Name(name) => self.table_by_name(name),
+            _ => None,
+        };
+
+        match table {
+            Some(table) => Ok(table.clone()),
+            None => Err(PyKeyError::new_err(match key {
+                AttrIndexSliceOrItem::Index(file_number) => file_number.to_string(),
+                AttrIndexSliceOrItem::Name(name) => name.to_string(),
+                _ => "tables are indexed by file number or name".to_string(),
+            })),
+        }
+    }
+}
+
 // <editor-fold desc="// DataFlexDB ...">
 
+// <editor-fold desc="// Helpers ...">
+
+/// Get the DataFlex version shared by every one of the supplied
+/// tables (or `Unknown` if they don't agree), i.e. the version
+/// whose layout the db's filelist entries are read and written with
+fn detect_version(tables: &[DataFlexTable]) -> Version {
+    let mut versions = tables
+        .iter()
+        .map(|table| &table.header.version)
+        .filter(|version| **version != Version::Unknown);
+
+    match versions.next() {
+        Some(first) if versions.all(|version| version == first) => first.clone(),
+        _ => Version::Unknown,
+    }
+}
+
+/// Get the path of the file with the supplied (lower-case) `extension`
+/// belonging to the table file at `table_path`, matching the case of the
+/// table file's extension
+fn companion_path(table_path: &str, extension: &str) -> String {
+    let path: &Path = Path::new(table_path);
+    let upper: bool = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.chars().all(|chr| chr.is_ascii_uppercase()));
+
+    string_from_path(
+        &path.with_extension(iif!(upper, extension.to_uppercase(), extension.to_string())),
+        Some(false),
+    )
+}
+
+/// Get the name a filelist entry's table is known by in DataFlex programs
+fn dataflex_file_name(entry: &FileListEntry) -> &str {
+    match entry.dataflex_name.as_deref() {
+        Some(name) if !name.is_empty() => name,
+        _ => root_file_name(entry),
+    }
+}
+
+/// Get the file name portion of a filelist entry's root name. Root names
+/// may include a directory, but tables are always loaded from the db's
+/// own directory.
+fn root_file_name(entry: &FileListEntry) -> &str {
+    entry
+        .root_name
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+}
+
+// </editor-fold desc="// Helpers ...">
+
 // <editor-fold desc="// Tests ...">
 
 #[cfg(test)]
 mod tests {
     #![allow(unused_imports)]
-    use super::DataFlexDB;
+    use super::{DataFlexDB, FailedTable};
+    use crate::enums::{DiagnosticKind, Version};
+    use crate::options::OpenOptions;
+    use crate::structs::{
+        Column, DataFlexTable, Diagnostic, FileList, FileListEntry, Header, Relationship,
+        TableDefinition,
+    };
+    use crate::AttrIndexSliceOrItem;
+    use std::collections::HashMap;
 
     #[test]
     /// Test that the `DataFlexDB` structure correctly handles table data
     fn gets_dbs() {
         todo!()
     }
+
+    #[test]
+    /// Test that filelists are read using the detected (or forced) version
+    fn detects_filelist_versions() {
+        let dev_data: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/dev_data");
+
+        for (forced, expected) in [
+            (Version::Unknown, Version::V30),
+            (Version::V30, Version::V30),
+            (Version::V23B, Version::V23B),
+        ] {
+            let options = OpenOptions {
+                read_only: true,
+                filelist_version: forced,
+                ..OpenOptions::default()
+            };
+            let db = DataFlexDB::open(dev_data, options).unwrap();
+
+            assert_eq!(db.filelist.version, expected);
+            assert_eq!(
+                db.filelist
+                    .entry(1)
+                    .and_then(|entry| entry.dataflex_name.as_deref()),
+                Some("dev_data")
+            );
+        }
+    }
+
+    #[test]
+    /// Test that every skipped structure is reported exactly once
+    fn reports_diagnostics() {
+        let mut header = Header::default();
+
+        header.diagnostics = vec![Diagnostic::new(
+            DiagnosticKind::Index,
+            "/db/customer.dat",
+            160,
+            "bad index",
+        )];
+
+        let mut db = DataFlexDB {
+            tables: vec![DataFlexTable {
+                header,
+                ..DataFlexTable::default()
+            }],
+            failed_tables: vec![FailedTable {
+                file_number: 7,
+                root_name: "ORDHEAD".to_string(),
+                path: "/db/ORDHEAD.dat".to_string(),
+                reason: "table file not found".to_string(),
+            }],
+            ..DataFlexDB::default()
+        };
+
+        db.filelist.diagnostics = vec![Diagnostic::new(
+            DiagnosticKind::FileListSlot,
+            "/db/filelist.cfg",
+            384,
+            "bad slot",
+        )];
+
+        assert_eq!(
+            db.diagnostics()
+                .iter()
+                .map(|diagnostic| (diagnostic.kind.clone(), diagnostic.offset))
+                .collect::<Vec<(DiagnosticKind, u64)>>(),
+            vec![
+                (DiagnosticKind::FileListSlot, 384),
+                (DiagnosticKind::Index, 160),
+                (DiagnosticKind::Table, 0),
+            ]
+        );
+    }
+
+    #[test]
+    /// Test that tables are indexed by filelist number and name
+    fn indexes_tables() {
+        let entry = |file_number: usize, root_name: &str, dataflex_name: &str| FileListEntry {
+            file_number,
+            root_name: root_name.to_string(),
+            dataflex_name: Some(dataflex_name.to_string()),
+            description: None,
+        };
+        let table = |path: &str| {
+            let mut header = Header::default();
+
+            header.filepath = path.to_string();
+
+            DataFlexTable {
+                header,
+                ..DataFlexTable::default()
+            }
+        };
+
+        let db = DataFlexDB {
+            filelist: FileList::from_iter([
+                FileListEntry::default(),
+                entry(2, "CUSTOMER", "CUSTOMER"),
+                entry(7, "ORDHEAD", "ORDERS"),
+            ]),
+            tables: vec![table("/db/ordhead.dat"), table("/db/customer.dat")],
+            ..DataFlexDB::default()
+        };
+
+        assert_eq!(db[2].header.filepath, "/db/customer.dat");
+        assert_eq!(db[7].header.filepath, "/db/ordhead.dat");
+        assert_eq!(db["orders"].header.filepath, "/db/ordhead.dat");
+        assert_eq!(db["OrdHead"].header.filepath, "/db/ordhead.dat");
+        assert!(db.table_by_number(1).is_none());
+        assert!(db.table_by_name("INVOICE").is_none());
+
+        assert_eq!(
+            db.get(AttrIndexSliceOrItem::Index(7))
+                .map(|tbl| tbl.header.filepath.as_str()),
+            Some("/db/ordhead.dat")
+        );
+        assert_eq!(
+            db.get(AttrIndexSliceOrItem::Name("customer"))
+                .map(|tbl| tbl.header.filepath.as_str()),
+            Some("/db/customer.dat")
+        );
+        assert!(db.get(AttrIndexSliceOrItem::Index(-1)).is_none());
+        assert!(db.get(AttrIndexSliceOrItem::Index(0)).is_none());
+    }
+
+    #[test]
+    /// Test that related columns are resolved into a relationship graph
+    fn resolves_relationships() {
+        let entry = |file_number: usize, root_name: &str| FileListEntry {
+            file_number,
+            root_name: root_name.to_string(),
+            dataflex_name: Some(root_name.to_string()),
+            description: None,
+        };
+        let column = |name: &str, related: Option<(u64, u64)>| Column {
+            name: name.to_string(),
+            related_file: related.map(|pair| pair.0),
+            related_field: related.map(|pair| pair.1),
+            ..Column::default()
+        };
+        let table = |path: &str, columns: Vec<Column>| {
+            let mut header = Header::default();
+
+            header.filepath = path.to_string();
+            header.columns = columns;
+
+            DataFlexTable {
+                header,
+                ..DataFlexTable::default()
+            }
+        };
+
+        let db = DataFlexDB {
+            filelist: FileList::from_iter([
+                FileListEntry::default(),
+                entry(2, "CUSTOMER"),
+                entry(7, "ORDHEAD"),
+                entry(8, "ORDNOTE"),
+            ]),
+            tables: vec![
+                table(
+                    "/db/customer.dat",
+                    vec![column("NUMBER", None), column("NAME", None)],
+                ),
+                table(
+                    "/db/ordhead.dat",
+                    vec![
+                        column("ORDER_NO", None),
+                        column("CUST_NO", Some((2, 1))),
+                        column("SHIP_TO", Some((9, 1))),
+                    ],
+                ),
+                table(
+                    "/db/ordnote.dat",
+                    vec![column("ORDER_REC", Some((7, 0))), column("NOTE", None)],
+                ),
+            ],
+            ..DataFlexDB::default()
+        };
+
+        let graph = db.relationships();
+
+        assert_eq!(graph.tables, vec!["CUSTOMER", "ORDHEAD", "ORDNOTE"]);
+        assert_eq!(
+            graph.relationships,
+            vec![
+                Relationship {
+                    child_file: 7,
+                    child_table: "ORDHEAD".to_string(),
+                    child_column: "CUST_NO".to_string(),
+                    parent_file: 2,
+                    parent_table: "CUSTOMER".to_string(),
+                    parent_column: "NUMBER".to_string(),
+                },
+                // Field 0 is the parent's record number
+                Relationship {
+                    child_file: 8,
+                    child_table: "ORDNOTE".to_string(),
+                    child_column: "ORDER_REC".to_string(),
+                    parent_file: 7,
+                    parent_table: "ORDHEAD".to_string(),
+                    parent_column: "RECNUM".to_string(),
+                },
+            ]
+        );
+
+        assert_eq!(
+            db.join("ordhead", "CUSTOMER").unwrap().to_sql(),
+            "ORDHEAD INNER JOIN CUSTOMER ON ORDHEAD.CUST_NO = CUSTOMER.NUMBER"
+        );
+        assert!(db.join("ORDNOTE", "ORDHEAD").is_err());
+    }
+
+    #[test]
+    /// Test that missing tag files are written and columns renamed
+    fn regenerates_tags() {
+        let dir = std::env::temp_dir().join(format!("ferroflex-regen-{}", std::process::id()));
+        let table_path = dir.join("CUSTOMER.DAT").to_string_lossy().to_string();
+
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut header = Header::default();
+
+        header.filepath = table_path;
+        header.columns = vec![Column::default(), Column::default()];
+
+        let mut db = DataFlexDB {
+            filelist: FileList::from_iter([
+                FileListEntry::default(),
+                FileListEntry {
+                    file_number: 1,
+                    root_name: "CUSTOMER".to_string(),
+                    dataflex_name: Some("CUSTOMERS".to_string()),
+                    description: None,
+                },
+            ]),
+            tables: vec![DataFlexTable {
+                header,
+                ..DataFlexTable::default()
+            }],
+            ..DataFlexDB::default()
+        };
+
+        let names = |table: &str, columns: &[&str]| {
+            HashMap::from([(
+                table.to_string(),
+                columns.iter().map(|name| name.to_string()).collect(),
+            )])
+        };
+
+        assert!(db
+            .regenerate_tags(&names("VENDOR", &["NUMBER"]), false)
+            .is_err());
+        assert!(db
+            .regenerate_tags(&names("customers", &["NUMBER"]), false)
+            .is_err());
+
+        let written = db
+            .regenerate_tags(&names("customers", &["NUMBER", "NAME"]), false)
+            .unwrap();
+
+        assert_eq!(
+            written,
+            vec![dir.join("CUSTOMER.TAG").to_string_lossy().to_string()]
+        );
+        assert_eq!(std::fs::read(&written[0]).unwrap(), b"NUMBER\r\nNAME\r\n");
+        assert_eq!(db["CUSTOMER"]._column_names(), vec!["NUMBER", "NAME"]);
+        assert!(db
+            .regenerate_tags(&names("customer", &["NO", "NAME"]), false)
+            .unwrap()
+            .is_empty());
+
+        let definition = TableDefinition {
+            file_number: Some(1),
+            columns: ["NO", "TITLE"]
+                .iter()
+                .map(|name| Column {
+                    name: name.to_string(),
+                    ..Column::default()
+                })
+                .collect(),
+            ..TableDefinition::default()
+        };
+
+        assert!(db
+            .regenerate_tags_from_definitions(Some(std::slice::from_ref(&definition)), false)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            db.regenerate_tags_from_definitions(Some(&[definition]), true)
+                .unwrap(),
+            written
+        );
+        assert_eq!(std::fs::read(&written[0]).unwrap(), b"NO\r\nTITLE\r\n");
+        assert_eq!(db["CUSTOMER"]._column_names(), vec!["NO", "TITLE"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
 
 // </editor-fold desc="// Tests ...">
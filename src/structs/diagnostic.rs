@@ -0,0 +1,154 @@
+// A structured record of a problem encountered while loading a DataFlex "database"
+
+// Standard Library Imports
+use std::fmt;
+
+// Third-Party Imports
+use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
+use pyo3::prelude::*;
+use pyo3::PyResult;
+use serde::{Deserialize, Serialize};
+
+// Crate-Level Imports
+use crate::enums::DiagnosticKind;
+use crate::exceptions::DataError;
+
+// <editor-fold desc="// Diagnostic ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+/// A structure (table, filelist slot, index, or column) that
+/// was skipped while loading a DataFlex "database" and why
+pub struct Diagnostic {
+    /// The kind of structure that was skipped
+    pub kind: DiagnosticKind,
+    #[pyo3(get)]
+    /// The on-disk path of the file containing the structure
+    pub path: String,
+    #[pyo3(get)]
+    /// The byte offset of the structure within its file
+    pub offset: u64,
+    #[pyo3(get)]
+    /// Why the structure was skipped
+    pub reason: String,
+}
+
+unsafe impl Send for Diagnostic {}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at byte {} of '{}': {}",
+            self.kind, self.offset, self.path, self.reason
+        )
+    }
+}
+
+impl Diagnostic {
+    // <editor-fold desc="// 'Private' Methods ...">
+
+    pub(crate) fn _as_pretty_table(&self) -> String {
+        PrettyTable::from_iter([
+            PrettyRow::from(vec![
+                PrettyCell::new("kind"),
+                PrettyCell::new(&self.kind.to_string()),
+            ]),
+            PrettyRow::from(vec![PrettyCell::new("path"), PrettyCell::new(&self.path)]),
+            PrettyRow::from(vec![
+                PrettyCell::new("offset"),
+                PrettyCell::new(&self.offset.to_string()),
+            ]),
+            PrettyRow::from(vec![
+                PrettyCell::new("reason"),
+                PrettyCell::new(&self.reason),
+            ]),
+        ])
+        .to_string()
+    }
+
+    /// Re-anchor a diagnostic recorded against a slice of a
+    /// file to the file itself
+    pub(crate) fn _relative_to(self, path: &str, base_offset: u64) -> Diagnostic {
+        Diagnostic {
+            path: path.to_string(),
+            offset: self.offset + base_offset,
+            ..self
+        }
+    }
+
+    // </editor-fold desc="// 'Private' Methods ...">
+
+    // <editor-fold desc="// Public Methods ...">
+
+    pub fn new<P: AsRef<str>, R: ToString>(
+        kind: DiagnosticKind,
+        path: P,
+        offset: u64,
+        reason: R,
+    ) -> Diagnostic {
+        Diagnostic {
+            kind,
+            path: path.as_ref().to_string(),
+            offset,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Raise a `DataError` for the first of the supplied diagnostics (if any),
+    /// which is how "strict" mode turns diagnostics into hard errors
+    pub fn ensure_none(diagnostics: &[Diagnostic]) -> PyResult<()> {
+        match diagnostics.first() {
+            Some(diagnostic) => Err(DataError::new_err(diagnostic.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    // </editor-fold desc="// Public Methods ...">
+}
+
+#[pymethods]
+impl Diagnostic {
+    #[getter]
+    /// The kind of structure that was skipped
+    fn get_kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a single line of text
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+// </editor-fold desc="// Diagnostic ...">
+
+// <editor-fold desc="// Tests ...">
+
+#[cfg(test)]
+mod tests {
+    use super::Diagnostic;
+    use crate::enums::DiagnosticKind;
+
+    #[test]
+    /// Test that `Diagnostic`s are anchored and rendered as expected
+    fn describes_problems() {
+        let diagnostic = Diagnostic::new(DiagnosticKind::Column, "", 16, "bad column")
+            ._relative_to("/db/orders.dat", 0xC4);
+
+        assert_eq!(diagnostic.offset, 0xD4);
+        assert_eq!(
+            diagnostic.to_string(),
+            "COLUMN at byte 212 of '/db/orders.dat': bad column"
+        );
+
+        assert!(Diagnostic::ensure_none(&[]).is_ok());
+    }
+}
+
+// </editor-fold desc="// Tests ...">
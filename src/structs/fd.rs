@@ -0,0 +1,1180 @@
+// Structured representations of DataFlex `.FD` and `.DEF` table definition files
+
+// Standard Library Imports
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Third-Party Imports
+use caseless::compatibility_caseless_match_str as cl_eq;
+use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::PyResult;
+use serde::{Deserialize, Serialize};
+
+// Crate-Level Imports
+use crate::enums::{DataType, DefinitionFormat, Encoding, IndexCollation, IndexType};
+use crate::iif;
+use crate::structs::tagfile::find_companion_file;
+use crate::structs::{Column, FieldSegment, Header, Index};
+use crate::utils::{bytes_from_file, string_from_path};
+
+// <editor-fold desc="// TableDefinition ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+#[pyo3(text_signature = "(filepath: str)")]
+/// A table's layout as described by a DataFlex `.FD` or `.DEF` file
+pub struct TableDefinition {
+    #[pyo3(get)]
+    /// The definition file's on-disk path
+    pub filepath: String,
+    /// The kind of file the definition was read from
+    pub format: DefinitionFormat,
+    #[pyo3(get)]
+    /// The table's DataFlex file number (if the file names it)
+    pub file_number: Option<u64>,
+    #[pyo3(get)]
+    /// The name of the table's on-disk file (for `.FD` files,
+    /// the definition file's own name)
+    pub root_name: String,
+    #[pyo3(get)]
+    /// The table's DataFlex (logical) name (if the file names it)
+    pub dataflex_name: Option<String>,
+    #[pyo3(get)]
+    /// The table's user display name (if the file names it)
+    pub description: Option<String>,
+    #[pyo3(get)]
+    /// The table's columns (in field number order). Columns read from
+    /// `.FD` files only carry a name and a coarse data type.
+    pub columns: Vec<Column>,
+    #[pyo3(get)]
+    /// The table's indexes (only `.DEF` files describe indexes)
+    pub indexes: Vec<Index>,
+    #[pyo3(get)]
+    /// The length (in bytes) of one of the table's
+    /// records (only `.DEF` files record it)
+    pub record_length: Option<u64>,
+    #[pyo3(get)]
+    /// The maximum number of records the table can
+    /// hold (only `.DEF` files record it)
+    pub max_record_count: Option<u64>,
+}
+
+unsafe impl Send for TableDefinition {}
+
+impl fmt::Display for TableDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "TableDefinition<'{}' | format: {} | columns: {} | indexes: {}>",
+            self.root_name,
+            self.format,
+            self.columns.len(),
+            self.indexes.len()
+        )
+    }
+}
+
+impl TableDefinition {
+    // <editor-fold desc="// 'Private' Methods ...">
+
+    pub(crate) fn _as_pretty_table(&self) -> String {
+        PrettyTable::from_iter([
+            PrettyRow::from(vec![
+                PrettyCell::new("filepath"),
+                PrettyCell::new(&self.filepath),
+            ]),
+            PrettyRow::from(vec![
+                PrettyCell::new("format"),
+                PrettyCell::new(&self.format.to_string()),
+            ]),
+            PrettyRow::from(vec![
+                PrettyCell::new("root_name"),
+                PrettyCell::new(&self.root_name),
+            ]),
+            PrettyRow::from(vec![
+                PrettyCell::new("columns"),
+                PrettyCell::new(format!(" {} ", self.column_names().join(" ¦ ")).as_str()),
+            ]),
+            PrettyRow::from(vec![
+                PrettyCell::new("indexes"),
+                PrettyCell::new(&self.indexes.len().to_string()),
+            ]),
+        ])
+        .to_string()
+    }
+
+    /// The name the table is known by in DataFlex programs
+    fn _name(&self) -> &str {
+        match self.dataflex_name.as_deref() {
+            Some(name) if !name.is_empty() => name,
+            _ => self.root_name.as_str(),
+        }
+    }
+
+    /// Get the (1-based) number of the column named `name`
+    /// (`RECNUM` being column 0)
+    fn _column_number(&self, name: &str) -> PyResult<u8> {
+        if cl_eq(name, "RECNUM") {
+            return Ok(0);
+        }
+
+        match self
+            .columns
+            .iter()
+            .position(|column| cl_eq(&column.name, name))
+        {
+            Some(position) => Ok((position + 1) as u8),
+            None => Err(PyValueError::new_err(format!(
+                "index segment '{}' names an unknown field",
+                name
+            ))),
+        }
+    }
+
+    // </editor-fold desc="// 'Private' Methods ...">
+
+    // <editor-fold desc="// Public Methods ...">
+
+    /// Parse the `#REPLACE` macros of a `.FD` file, e.g.:
+    ///
+    /// ```text
+    /// #REPLACE FILE1 CUSTOMER
+    /// #REPLACE CUSTOMER.RECNUM |FN1,0
+    /// #REPLACE CUSTOMER.NAME |FS1,1
+    /// ```
+    pub fn from_fd_str(text: &str) -> PyResult<TableDefinition> {
+        let mut definition = TableDefinition {
+            format: DefinitionFormat::Fd,
+            ..TableDefinition::default()
+        };
+        let mut fields: Vec<(u64, Column)> = Vec::new();
+
+        for line in text.lines() {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+
+            if tokens.len() < 3 || !cl_eq(tokens[0], "#REPLACE") {
+                continue;
+            }
+
+            let (name, value) = (tokens[1], tokens[2]);
+
+            // `#REPLACE FILE{number} {DataFlex name}`
+            if let Some(number) = name
+                .get(..4)
+                .filter(|prefix| cl_eq(prefix, "FILE"))
+                .and_then(|_| name[4..].parse::<u64>().ok())
+            {
+                if definition.file_number.is_none() {
+                    definition.file_number = Some(number);
+                    definition.dataflex_name = Some(value.to_string());
+                }
+
+                continue;
+            }
+
+            // `#REPLACE {table}.{field} |F{type}{file},{field}`
+            let (kind, numbers) = match value.get(..2).filter(|prefix| cl_eq(prefix, "|F")) {
+                Some(_) if value.len() > 3 && value.as_bytes()[2].is_ascii() => {
+                    (value.as_bytes()[2], &value[3..])
+                }
+                _ => continue,
+            };
+
+            let field: u64 = match numbers
+                .split_once(',')
+                .and_then(|(_, field)| field.parse().ok())
+            {
+                Some(field) => field,
+                None => continue,
+            };
+
+            // Field 0 is the implicit record number
+            if field == 0 {
+                continue;
+            }
+
+            fields.push((
+                field,
+                Column {
+                    name: name.rsplit('.').next().unwrap_or(name).to_string(),
+                    data_type: match kind.to_ascii_uppercase() {
+                        b'N' => DataType::Int,
+                        b'D' => DataType::Date,
+                        b'S' => DataType::Ascii,
+                        _ => DataType::Unknown,
+                    },
+                    ..Column::default()
+                },
+            ));
+        }
+
+        if fields.is_empty() {
+            return Err(PyValueError::new_err("no field definitions were found"));
+        }
+
+        fields.sort_by_key(|(field, _)| *field);
+        definition.columns = fields.into_iter().map(|(_, column)| column).collect();
+
+        Ok(definition)
+    }
+
+    /// Parse the printed table definition in a `.DEF` file
+    pub fn from_def_str(text: &str) -> PyResult<TableDefinition> {
+        #[derive(PartialEq)]
+        enum Section {
+            Preamble,
+            Fields,
+            Indexes,
+        }
+
+        let mut definition = TableDefinition {
+            format: DefinitionFormat::Def,
+            ..TableDefinition::default()
+        };
+        let mut section = Section::Preamble;
+        let mut index_fields: Vec<(Vec<String>, bool, IndexType)> = Vec::new();
+
+        for line in text.lines() {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+
+            if tokens.is_empty() || tokens[0].starts_with('-') {
+                continue;
+            }
+
+            if cl_eq(tokens[0], "NUM") && tokens.get(1).is_some_and(|t| cl_eq(t, "FIELD")) {
+                section = Section::Fields;
+                continue;
+            }
+
+            if cl_eq(tokens[0], "INDEX#") {
+                section = Section::Indexes;
+                continue;
+            }
+
+            let number: Option<u64> = tokens[0].parse().ok();
+
+            match section {
+                Section::Preamble => {
+                    let (key, value) = match line.split_once(':') {
+                        Some((key, value)) => (key.trim().to_uppercase(), value.trim()),
+                        None => continue,
+                    };
+
+                    match key.as_str() {
+                        "FILE DEFINITION FOR FILE" => {
+                            definition.file_number =
+                                value.split_once("(#").and_then(|(_, rest)| {
+                                    rest.trim_end_matches(')').trim().parse().ok()
+                                });
+                        }
+                        "FILE ROOT NAME" => definition.root_name = value.to_string(),
+                        "USER DISPLAY NAME" => definition.description = Some(value.to_string()),
+                        "DATAFLEX FILE NAME" => definition.dataflex_name = Some(value.to_string()),
+                        "RECORD LENGTH" => definition.record_length = leading_number(value),
+                        "MAX NUMBER OF RECORDS" => {
+                            definition.max_record_count = leading_number(value)
+                        }
+                        _ => {}
+                    }
+                }
+                Section::Fields => {
+                    if number.is_none() || tokens.len() < 5 {
+                        continue;
+                    }
+
+                    definition.columns.push(column_from_def(&tokens)?);
+                }
+                Section::Indexes => match number {
+                    Some(_) if tokens.len() >= 4 => index_fields.push((
+                        vec![tokens[1].to_string()],
+                        cl_eq(tokens[3], "YES"),
+                        IndexType::from(tokens.iter().any(|token| cl_eq(token, "BATCH"))),
+                    )),
+                    // Additional segments of the previous index
+                    None if tokens.len() >= 3
+                        && matches!(tokens[2].to_uppercase().as_str(), "YES" | "NO") =>
+                    {
+                        if let Some((fields, uppercase, _)) = index_fields.last_mut() {
+                            fields.push(tokens[0].to_string());
+                            *uppercase |= cl_eq(tokens[2], "YES");
+                        }
+                    }
+                    _ => {}
+                },
+            }
+        }
+
+        if definition.columns.is_empty() {
+            return Err(PyValueError::new_err("no field definitions were found"));
+        }
+
+        for (fields, uppercase, r#type) in index_fields {
+            let segments: Vec<FieldSegment> = fields
+                .iter()
+                .enumerate()
+                .map(|(segment, name)| {
+                    Ok(FieldSegment {
+                        column: definition._column_number(name)?,
+                        segment: segment as u8,
+                    })
+                })
+                .collect::<PyResult<Vec<FieldSegment>>>()?;
+
+            definition.indexes.push(Index {
+                r#type,
+                field_count: segments.len() as u8,
+                segments,
+                collation: match uppercase {
+                    true => IndexCollation::Uppercase,
+                    false => IndexCollation::Default,
+                },
+            });
+        }
+
+        Ok(definition)
+    }
+
+    /// Read the `.FD` or `.DEF` file at `filepath`, decoding
+    /// its text using `encoding` (normally the same encoding
+    /// as the table it describes)
+    pub fn from_path<T: AsRef<str>>(filepath: T, encoding: &Encoding) -> PyResult<TableDefinition> {
+        let filepath: &str = filepath.as_ref();
+
+        let text: String = bytes_from_file(filepath, None::<u64>, None::<u64>)?
+            .iter()
+            .map(|byte| encoding.decode_byte(*byte))
+            .collect();
+
+        let extension: &str = Path::new(filepath)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+
+        let mut definition = if cl_eq(extension, "fd") {
+            TableDefinition::from_fd_str(&text)?
+        } else if cl_eq(extension, "def") {
+            TableDefinition::from_def_str(&text)?
+        } else {
+            return Err(PyValueError::new_err(format!(
+                "'{}' isn't a `.FD` or `.DEF` file",
+                filepath
+            )));
+        };
+
+        // `.FD` files don't name the table's on-disk file, but
+        // they're found by it (see `find_for_table`)
+        if definition.root_name.is_empty() {
+            definition.root_name = Path::new(filepath)
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default()
+                .to_string();
+        }
+
+        definition.filepath = filepath.to_string();
+
+        Ok(definition)
+    }
+
+    /// Describe the table whose header is supplied (the header doesn't
+    /// know the table's file number or DataFlex name, so neither is set)
+    pub fn from_header(header: &Header) -> TableDefinition {
+        TableDefinition {
+            root_name: header.file_root_name.trim().to_string(),
+            columns: header.columns.clone(),
+            // Header indexes carry (zeroed) padding segments past their field count
+            indexes: header
+                .indexes
+                .iter()
+                .map(|index| Index {
+                    segments: index
+                        .segments
+                        .iter()
+                        .take(index.field_count as usize)
+                        .cloned()
+                        .collect(),
+                    ..index.clone()
+                })
+                .collect(),
+            record_length: Some(header.record_length),
+            max_record_count: Some(header.max_record_count),
+            ..TableDefinition::default()
+        }
+    }
+
+    /// Render the definition as the `#REPLACE` macros of a `.FD` file
+    /// (which requires the table's file number)
+    pub fn to_fd_string(&self) -> PyResult<String> {
+        let file_number: u64 = self.file_number.ok_or_else(|| {
+            PyValueError::new_err("`.FD` files can't be written without a file number")
+        })?;
+        let name: &str = self._name();
+
+        let mut lines: Vec<String> = vec![
+            format!("#REPLACE FILE{} {}", file_number, name),
+            format!("#REPLACE {}.Recnum |FN{},0", name, file_number),
+        ];
+
+        lines.extend(self.columns.iter().enumerate().map(|(position, column)| {
+            format!(
+                "#REPLACE {}.{} |F{}{},{}",
+                name,
+                column.name,
+                match column.data_type {
+                    DataType::Int | DataType::Float => 'N',
+                    DataType::Date => 'D',
+                    _ => 'S',
+                },
+                file_number,
+                position + 1
+            )
+        }));
+
+        Ok(lines.iter().map(|line| format!("{}\r\n", line)).collect())
+    }
+
+    /// Render the definition as a printed `.DEF` file, describing related
+    /// columns by their file and field numbers
+    pub fn to_def_string(&self) -> String {
+        self.to_def_string_with(|_, _| None)
+    }
+
+    /// Render the definition as a printed `.DEF` file, describing each
+    /// related column as the `FILE.FIELD` returned by `related_name` for
+    /// its file and field numbers (if any) followed by the numbers. Index
+    /// lengths and levels can't be derived from a header, so they're left
+    /// out of the index list.
+    pub fn to_def_string_with<F: Fn(u64, u64) -> Option<String>>(&self, related_name: F) -> String {
+        let rule: String = "-".repeat(77);
+        let mut lines: Vec<String> = vec![
+            rule.clone(),
+            format!(
+                "  FILE DEFINITION FOR FILE: {}{}",
+                self._name(),
+                self.file_number
+                    .map_or(String::new(), |number| format!(" (# {})", number))
+            ),
+            rule.clone(),
+            "  DRIVER NAME               : DATAFLEX".to_string(),
+            format!("  FILE ROOT NAME            : {}", self.root_name),
+            format!(
+                "  USER DISPLAY NAME         : {}",
+                self.description.as_deref().unwrap_or_else(|| self._name())
+            ),
+            format!("  DATAFLEX FILE NAME        : {}", self._name()),
+            rule.clone(),
+        ];
+
+        if let Some(record_length) = self.record_length {
+            let used: u64 = self
+                .columns
+                .iter()
+                .map(|column| (column.offset + column.length).saturating_sub(1))
+                .max()
+                .unwrap_or_default();
+
+            lines.push(format!(
+                "  RECORD LENGTH             : {:<9} ( USED: {} )",
+                record_length, used
+            ));
+        }
+
+        if let Some(max_record_count) = self.max_record_count {
+            lines.push(format!(
+                "  MAX NUMBER OF RECORDS     : {}",
+                max_record_count
+            ));
+        }
+
+        lines.extend([
+            rule,
+            String::new(),
+            "NUM  FIELD NAME       TYPE SIZE  OFFST IX   RELATES TO FILE.FIELD".to_string(),
+            "---  ---------------  ---- ----- ----- --   ---------------------------------"
+                .to_string(),
+        ]);
+
+        for (position, column) in self.columns.iter().enumerate() {
+            let (kind, size): (&str, String) = match column.data_type {
+                DataType::Int | DataType::Float => {
+                    let decimals: u64 = column.decimal_points * 2;
+
+                    (
+                        "NUM",
+                        format!(
+                            "{}.{}",
+                            (column.length * 2).saturating_sub(decimals),
+                            decimals
+                        ),
+                    )
+                }
+                DataType::Date => ("DAT", "6".to_string()),
+                DataType::Ascii => ("ASC", column.length.to_string()),
+                DataType::Text => ("TEX", column.length.to_string()),
+                DataType::Binary => ("BIN", column.length.to_string()),
+                DataType::Unknown => ("OVL", column.length.to_string()),
+            };
+
+            let relation: String = match (column.related_file, column.related_field) {
+                (Some(file), Some(field)) => match related_name(file, field) {
+                    Some(name) => format!("{} ({},{})", name, file, field),
+                    None => format!("({},{})", file, field),
+                },
+                _ => String::new(),
+            };
+
+            lines.push(format!(
+                "{:>3}  {:<15}  {:<4} {:>5} {:>5} {:>2}   {}",
+                position + 1,
+                column.name,
+                kind,
+                size,
+                column.offset,
+                column
+                    .main_index
+                    .map_or(String::new(), |index| index.to_string()),
+                relation
+            ));
+        }
+
+        if !self.indexes.is_empty() {
+            lines.extend([
+                String::new(),
+                String::new(),
+                "INDEX# FIELDS          DES U/C    SEGMENTS MODE".to_string(),
+                "------ --------------- --- ---    -------- -------".to_string(),
+            ]);
+        }
+
+        for (number, index) in self.indexes.iter().enumerate() {
+            let uppercase: &str = iif!(index.collation == IndexCollation::Uppercase, "YES", "NO");
+
+            if number > 0 {
+                lines.push(String::new());
+            }
+
+            for (position, segment) in index.segments.iter().enumerate() {
+                let name: String = match segment.column as usize {
+                    0 => "RECNUM".to_string(),
+                    column => self
+                        .columns
+                        .get(column - 1)
+                        .map_or(format!("FIELD{}", column), |column| column.name.clone()),
+                };
+
+                lines.push(match position {
+                    0 => format!(
+                        "{:>3}    {:<15} NO  {:<3}    {:>8} {}",
+                        number + 1,
+                        name,
+                        uppercase,
+                        index.segments.len(),
+                        iif!(index.r#type == IndexType::Batch, "BATCH", "ON-LINE")
+                    ),
+                    _ => format!("       {:<15} NO  {}", name, uppercase),
+                });
+            }
+        }
+
+        lines
+            .iter()
+            .map(|line| format!("{}\r\n", line.trim_end()))
+            .collect()
+    }
+
+    /// Write the definition to the `.FD` or `.DEF` file at
+    /// `filepath`, encoding its text using `encoding`
+    pub fn save<T: AsRef<str>>(&self, filepath: T, encoding: &Encoding) -> PyResult<()> {
+        self.save_with(filepath, encoding, |_, _| None)
+    }
+
+    /// Write the definition to the `.FD` or `.DEF` file at `filepath`,
+    /// encoding its text using `encoding` (see `to_def_string_with`
+    /// for the use of `related_name`)
+    pub fn save_with<T: AsRef<str>, F: Fn(u64, u64) -> Option<String>>(
+        &self,
+        filepath: T,
+        encoding: &Encoding,
+        related_name: F,
+    ) -> PyResult<()> {
+        let filepath: &str = filepath.as_ref();
+        let extension: &str = Path::new(filepath)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+
+        let text: String = if cl_eq(extension, "fd") {
+            self.to_fd_string()?
+        } else if cl_eq(extension, "def") {
+            self.to_def_string_with(related_name)
+        } else {
+            return Err(PyValueError::new_err(format!(
+                "'{}' isn't a `.FD` or `.DEF` file",
+                filepath
+            )));
+        };
+
+        fs::write(filepath, bytes_from_text(&text, encoding)?)?;
+
+        Ok(())
+    }
+
+    /// Find the `.FD` (or failing that, `.DEF`) file for the table at
+    /// `table_path`, searching the same directories as its tag file
+    pub fn find_for_table(
+        table_path: &Path,
+        search_paths: &[PathBuf],
+        ancestor_depth: usize,
+    ) -> Option<String> {
+        ["fd", "def"]
+            .iter()
+            .find_map(|extension| {
+                find_companion_file(table_path, extension, search_paths, ancestor_depth)
+            })
+            .map(|path| string_from_path(&path, Some(!path.is_absolute())))
+    }
+
+    /// The names of the table's columns (in column order)
+    pub fn column_names(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect()
+    }
+
+    /// Report every way in which the supplied header disagrees with the
+    /// definition. Column names are only compared if the header's names
+    /// came from a tag file, and everything but names and (coarse) data
+    /// types is skipped for `.FD` definitions, which don't record it.
+    pub fn compare(&self, header: &Header) -> Vec<DefinitionMismatch> {
+        let mut mismatches: Vec<DefinitionMismatch> = Vec::new();
+        let full: bool = self.format == DefinitionFormat::Def;
+
+        let mut check = |subject: &str, attribute: &str, found: String, expected: String| {
+            if found != expected {
+                mismatches.push(DefinitionMismatch {
+                    subject: subject.to_string(),
+                    attribute: attribute.to_string(),
+                    header: found,
+                    definition: expected,
+                });
+            }
+        };
+
+        check(
+            "table",
+            "column count",
+            header.columns.len().to_string(),
+            self.columns.len().to_string(),
+        );
+
+        for (number, (found, expected)) in
+            header.columns.iter().zip(self.columns.iter()).enumerate()
+        {
+            let subject: String = format!("column {} ({})", number + 1, expected.name);
+
+            if header.tag_path.is_some() && !cl_eq(&found.name, &expected.name) {
+                check(&subject, "name", found.name.clone(), expected.name.clone());
+            }
+
+            // `.FD` files only distinguish numbers, dates, and strings
+            let data_type = |column: &Column| -> String {
+                match (full, &column.data_type) {
+                    (false, DataType::Int | DataType::Float) => DataType::Int.to_string(),
+                    (false, DataType::Ascii | DataType::Text | DataType::Binary) => {
+                        DataType::Ascii.to_string()
+                    }
+                    (_, data_type) => data_type.to_string(),
+                }
+            };
+
+            check(&subject, "data type", data_type(found), data_type(expected));
+
+            if !full {
+                continue;
+            }
+
+            let optional = |value: Option<u64>| value.map_or("N/A".to_string(), |v| v.to_string());
+
+            check(
+                &subject,
+                "offset",
+                found.offset.to_string(),
+                expected.offset.to_string(),
+            );
+            check(
+                &subject,
+                "length",
+                found.length.to_string(),
+                expected.length.to_string(),
+            );
+            check(
+                &subject,
+                "decimal points",
+                found.decimal_points.to_string(),
+                expected.decimal_points.to_string(),
+            );
+            check(
+                &subject,
+                "main index",
+                optional(found.main_index),
+                optional(expected.main_index),
+            );
+            check(
+                &subject,
+                "related file",
+                optional(found.related_file),
+                optional(expected.related_file),
+            );
+            check(
+                &subject,
+                "related field",
+                optional(found.related_field),
+                optional(expected.related_field),
+            );
+        }
+
+        if !full {
+            return mismatches;
+        }
+
+        check(
+            "table",
+            "index count",
+            header.indexes.len().to_string(),
+            self.indexes.len().to_string(),
+        );
+
+        // Header indexes carry (zeroed) padding segments past their field count
+        let fields = |index: &Index| -> String {
+            index
+                .segments
+                .iter()
+                .take(index.field_count as usize)
+                .map(|segment| segment.column.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        if let Some(record_length) = self.record_length {
+            check(
+                "table",
+                "record length",
+                header.record_length.to_string(),
+                record_length.to_string(),
+            );
+        }
+
+        for (number, (found, expected)) in
+            header.indexes.iter().zip(self.indexes.iter()).enumerate()
+        {
+            let subject: String = format!("index {}", number + 1);
+
+            check(&subject, "fields", fields(found), fields(expected));
+            check(
+                &subject,
+                "type",
+                found.r#type.to_string(),
+                expected.r#type.to_string(),
+            );
+        }
+
+        mismatches
+    }
+
+    // </editor-fold desc="// Public Methods ...">
+}
+
+#[pymethods]
+impl TableDefinition {
+    #[new]
+    /// Read the `.FD` or `.DEF` file at `filepath`
+    fn py_new(filepath: &str) -> PyResult<TableDefinition> {
+        TableDefinition::from_path(filepath, &Encoding::default())
+    }
+
+    #[getter]
+    /// The kind of file the definition was read from
+    fn get_format(&self) -> String {
+        self.format.to_string()
+    }
+
+    #[pyo3(name = "column_names", text_signature = "($self) -> List[str]")]
+    /// The names of the table's columns (in column order)
+    fn py_column_names(&self) -> Vec<String> {
+        self.column_names()
+    }
+
+    #[staticmethod]
+    #[pyo3(
+        name = "from_header",
+        text_signature = "(header: Header) -> TableDefinition"
+    )]
+    /// Describe the table whose header is supplied
+    fn py_from_header(header: Header) -> TableDefinition {
+        TableDefinition::from_header(&header)
+    }
+
+    #[pyo3(name = "to_fd", text_signature = "($self) -> str")]
+    /// Render the definition as the `#REPLACE` macros of a `.FD` file
+    fn py_to_fd(&self) -> PyResult<String> {
+        self.to_fd_string()
+    }
+
+    #[pyo3(name = "to_def", text_signature = "($self) -> str")]
+    /// Render the definition as a printed `.DEF` file
+    fn py_to_def(&self) -> String {
+        self.to_def_string()
+    }
+
+    #[pyo3(name = "save", text_signature = "($self, filepath: str)")]
+    /// Write the definition to the `.FD` or `.DEF` file at `filepath`
+    fn py_save(&self, filepath: &str) -> PyResult<()> {
+        self.save(filepath, &Encoding::default())
+    }
+
+    #[pyo3(
+        name = "compare",
+        text_signature = "($self, header: Header) -> List[DefinitionMismatch]"
+    )]
+    /// Report every way in which `header` disagrees with the definition
+    fn py_compare(&self, header: Header) -> Vec<DefinitionMismatch> {
+        self.compare(&header)
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
+// </editor-fold desc="// TableDefinition ...">
+
+// <editor-fold desc="// DefinitionMismatch ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+/// A single disagreement between a table's header and its definition file
+pub struct DefinitionMismatch {
+    #[pyo3(get)]
+    /// The part of the table that disagrees (e.g. "column 3 (NAME)")
+    pub subject: String,
+    #[pyo3(get)]
+    /// The attribute that disagrees (e.g. "length")
+    pub attribute: String,
+    #[pyo3(get)]
+    /// The attribute's value according to the table's header
+    pub header: String,
+    #[pyo3(get)]
+    /// The attribute's value according to the definition file
+    pub definition: String,
+}
+
+unsafe impl Send for DefinitionMismatch {}
+
+impl fmt::Display for DefinitionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: header has '{}' but definition has '{}'",
+            self.subject, self.attribute, self.header, self.definition
+        )
+    }
+}
+
+#[pymethods]
+impl DefinitionMismatch {
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+// </editor-fold desc="// DefinitionMismatch ...">
+
+// <editor-fold desc="// Helpers ...">
+
+/// Parse the number at the start of a `.DEF` file value (e.g. the
+/// `8448` in `8448      ( USED: 8421 )`)
+fn leading_number(value: &str) -> Option<u64> {
+    value.split_whitespace().next()?.parse().ok()
+}
+
+/// Encode definition text using `encoding` (the way it's read back)
+fn bytes_from_text(text: &str, encoding: &Encoding) -> PyResult<Vec<u8>> {
+    text.chars()
+        .map(|chr| {
+            encoding.encode_char(chr).ok_or_else(|| {
+                PyValueError::new_err(format!("'{}' can't be written to a definition file", chr))
+            })
+        })
+        .collect()
+}
+
+/// Build a column from the tokens of a `.DEF` file's field line, i.e.
+/// `NUM NAME TYPE SIZE OFFSET [INDEX] [RELATED.FIELD (file,field)]`
+fn column_from_def(tokens: &[&str]) -> PyResult<Column> {
+    let invalid =
+        || PyValueError::new_err(format!("invalid field definition: '{}'", tokens.join(" ")));
+
+    let (digits, decimals): (u64, u64) = match tokens[3].split_once('.') {
+        Some((digits, decimals)) => (
+            digits.parse().map_err(|_| invalid())?,
+            decimals.parse().map_err(|_| invalid())?,
+        ),
+        None => (tokens[3].parse().map_err(|_| invalid())?, 0),
+    };
+
+    let mut rest: &[&str] = &tokens[5..];
+    let main_index: Option<u64> = match rest.first().and_then(|token| token.parse::<u64>().ok()) {
+        Some(index) => {
+            rest = &rest[1..];
+            iif!(index > 0, Some(index), None)
+        }
+        None => None,
+    };
+
+    let relation: String = rest.join("");
+    let (related_file, related_field): (Option<u64>, Option<u64>) = match relation
+        .rsplit_once('(')
+        .and_then(|(_, pair)| pair.trim_end_matches(')').split_once(','))
+    {
+        Some((file, field)) => (file.trim().parse().ok(), field.trim().parse().ok()),
+        None => (None, None),
+    };
+
+    // Numbers are stored as packed BCD (two digits per byte) and the
+    // header records their decimal places in bytes, not digits
+    let (data_type, length, decimal_points) = match tokens[2].to_uppercase().as_str() {
+        "NUM" => (
+            iif!(decimals > 0, DataType::Float, DataType::Int),
+            (digits + decimals).div_ceil(2),
+            decimals.div_ceil(2),
+        ),
+        "DAT" => (DataType::Date, 3, 0),
+        "ASC" => (DataType::Ascii, digits, 0),
+        "TEX" => (DataType::Text, digits, 0),
+        "BIN" => (DataType::Binary, digits, 0),
+        _ => (DataType::Unknown, digits, 0),
+    };
+
+    Ok(Column {
+        name: tokens[1].to_string(),
+        offset: tokens[4].parse().map_err(|_| invalid())?,
+        main_index,
+        decimal_points,
+        length,
+        data_type,
+        related_file,
+        related_field,
+    })
+}
+
+// </editor-fold desc="// Helpers ...">
+
+// <editor-fold desc="// Tests ...">
+
+#[cfg(test)]
+mod tests {
+    use super::TableDefinition;
+    use crate::enums::{DataType, Encoding, IndexCollation};
+    use crate::structs::{Column, FieldSegment, Header, Index};
+
+    const DEV_DATA_FD: &str = "#REPLACE FILE1 dev_data\r\n\
+        #REPLACE dev_data.Recnum |FN1,0\r\n\
+        #REPLACE dev_data.int_col |FN1,1\r\n\
+        #REPLACE dev_data.float_col |FN1,2\r\n\
+        #REPLACE dev_data.date_col |FD1,3\r\n\
+        #REPLACE dev_data.ascii_col |FS1,4\r\n";
+
+    const CUSTOMER_DEF: &str = "\
+-----------------------------------------------------------------------------
+  DATE: 05/27/2021      TIME: 18:02                                  PAGE:  1
+  FILE DEFINITION FOR FILE: CUSTOMER (# 2)
+-----------------------------------------------------------------------------
+  DRIVER NAME               : DATAFLEX
+  FILE ROOT NAME            : CUSTOMER
+  USER DISPLAY NAME         : Customers
+  DATAFLEX FILE NAME        : CUSTOMER
+-----------------------------------------------------------------------------
+  RECORD LENGTH             : 256       ( USED: 49 )
+-----------------------------------------------------------------------------
+
+NUM  FIELD NAME       TYPE SIZE  OFFST IX   RELATES TO FILE.FIELD
+---  ---------------  ---- ----- ----- --   ---------------------------------
+  1  NUMBER           NUM    6.0     1  1
+  2  NAME             ASC     30     4  2
+  3  BALANCE          NUM    8.2    34
+  4  SALES_REP        ASC      4    39      SALESREP.ID (5,1)
+  5  SINCE            DAT      6    43
+
+INDEX# FIELDS          DES U/C    LENGTH LEVELS SEGMENTS MODE
+------ --------------- --- ---    ------ ------ -------- -------
+  1    NUMBER          NO  NO       3      2        1    ON-LINE
+
+  2    NAME            NO  YES     33      4        2    ON-LINE
+       NUMBER          NO  NO
+";
+
+    #[test]
+    /// Test that `.FD` files are parsed into columns
+    fn parses_fd_files() {
+        let definition = TableDefinition::from_fd_str(DEV_DATA_FD).unwrap();
+
+        assert_eq!(definition.file_number, Some(1));
+        assert_eq!(definition.dataflex_name.as_deref(), Some("dev_data"));
+        assert!(definition.root_name.is_empty());
+        assert_eq!(
+            definition.column_names(),
+            vec!["int_col", "float_col", "date_col", "ascii_col"]
+        );
+        assert_eq!(definition.columns[2].data_type, DataType::Date);
+        assert!(TableDefinition::from_fd_str("// nothing to see here").is_err());
+    }
+
+    #[test]
+    /// Test that `.DEF` files are parsed and compared against headers
+    fn parses_def_files() {
+        let definition = TableDefinition::from_def_str(CUSTOMER_DEF).unwrap();
+
+        assert_eq!(definition.file_number, Some(2));
+        assert_eq!(definition.dataflex_name.as_deref(), Some("CUSTOMER"));
+        assert_eq!(definition.description.as_deref(), Some("Customers"));
+        assert_eq!(
+            definition.columns[2],
+            Column {
+                name: "BALANCE".to_string(),
+                offset: 34,
+                length: 5,
+                decimal_points: 1,
+                data_type: DataType::Float,
+                ..Column::default()
+            }
+        );
+        assert_eq!(definition.columns[3].related_file, Some(5));
+        assert_eq!(definition.columns[3].related_field, Some(1));
+        assert_eq!(definition.columns[4].length, 3);
+        assert_eq!(definition.indexes.len(), 2);
+        assert_eq!(definition.indexes[1].collation, IndexCollation::Uppercase);
+        assert_eq!(
+            definition.indexes[1].segments,
+            vec![
+                FieldSegment {
+                    column: 2,
+                    segment: 0
+                },
+                FieldSegment {
+                    column: 1,
+                    segment: 1
+                },
+            ]
+        );
+
+        let mut header = Header::default();
+
+        header.record_length = definition.record_length.unwrap_or_default();
+        header.columns = definition.columns.clone();
+        header.indexes = definition
+            .indexes
+            .iter()
+            .map(|index| Index {
+                segments: [index.segments.clone(), vec![FieldSegment::default(); 4]].concat(),
+                ..index.clone()
+            })
+            .collect();
+
+        assert!(definition.compare(&header).is_empty());
+
+        header.columns[1].length = 40;
+        header.columns.pop();
+
+        assert_eq!(
+            definition
+                .compare(&header)
+                .iter()
+                .map(|mismatch| mismatch.to_string())
+                .collect::<Vec<String>>(),
+            vec![
+                "table column count: header has '4' but definition has '5'",
+                "column 2 (NAME) length: header has '40' but definition has '30'",
+            ]
+        );
+    }
+
+    #[test]
+    /// Test that definitions written from a header read back the same
+    fn writes_definitions() {
+        let mut definition = TableDefinition::from_def_str(CUSTOMER_DEF).unwrap();
+
+        definition.filepath = String::new();
+        definition.max_record_count = None;
+
+        let def: String = definition
+            .to_def_string_with(|file, field| Some(format!("FILE{}.FIELD{}", file, field)));
+
+        assert!(def.contains(
+            "\r\n  3  BALANCE          NUM    8.2    34\r\n  \
+             4  SALES_REP        ASC      4    39      FILE5.FIELD1 (5,1)\r\n"
+        ));
+        assert!(def.contains("\r\n  USER DISPLAY NAME         : Customers\r\n"));
+        assert!(def.contains("\r\n  2    NAME            NO  YES           2 ON-LINE\r\n"));
+        assert_eq!(TableDefinition::from_def_str(&def).unwrap(), definition);
+
+        let fd: String = definition.to_fd_string().unwrap();
+
+        assert!(fd.starts_with("#REPLACE FILE2 CUSTOMER\r\n#REPLACE CUSTOMER.Recnum |FN2,0\r\n"));
+        assert!(fd.contains("#REPLACE CUSTOMER.SINCE |FD2,5\r\n"));
+        assert_eq!(
+            TableDefinition::from_fd_str(&fd).unwrap().column_names(),
+            definition.column_names()
+        );
+        assert!(TableDefinition::default().to_fd_string().is_err());
+    }
+
+    #[test]
+    /// Test that definition files are read and written using an encoding
+    fn uses_definition_encodings() {
+        let dir = std::env::temp_dir().join(format!("ferroflex-defs-{}", std::process::id()));
+        let path: String = dir.join("customer.def").to_string_lossy().to_string();
+        let mut definition = TableDefinition::from_def_str(CUSTOMER_DEF).unwrap();
+
+        std::fs::create_dir_all(&dir).unwrap();
+        definition.description = Some("Clientes Españoles".to_string());
+        definition.save(&path, &Encoding::Cp850).unwrap();
+
+        // 'ñ' is 0xA4 in code page 850 (but '¤' in Latin-1)
+        assert!(std::fs::read(&path)
+            .unwrap()
+            .windows(3)
+            .any(|window| window == b"a\xA4o"));
+        assert_eq!(
+            TableDefinition::from_path(&path, &Encoding::Cp850)
+                .unwrap()
+                .description,
+            definition.description
+        );
+        assert_eq!(
+            TableDefinition::from_path(&path, &Encoding::Latin1)
+                .unwrap()
+                .description
+                .as_deref(),
+            Some("Clientes Espa¤oles")
+        );
+        assert!(definition.save(&path, &Encoding::Raw).is_ok());
+
+        definition.description = Some("Ωmega".to_string());
+
+        assert!(definition.save(&path, &Encoding::Cp850).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+// </editor-fold desc="// Tests ...">
@@ -1,34 +1,76 @@
 // A structured representation of DataFlex's `filelist.cfg` file
 
 // Standard Library Imports
+use std::collections::BTreeMap;
 use std::fmt;
+use std::fs;
 use std::iter::IntoIterator;
+use std::ops::Range;
 use std::path::Path;
 
 // Third-Party Imports
 use caseless::compatibility_caseless_match_str as cl_eq;
 use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
-use pyo3::exceptions::{PyFileNotFoundError, PyIndexError, PyValueError};
+use pyo3::exceptions::{PyFileNotFoundError, PyIndexError, PyKeyError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 // Crate-Level Imports
-use crate::utils::{bytes_from_file, path_from_string, string_from_bytes};
+use crate::enums::{DiagnosticKind, Encoding, Version, Whitespace};
+use crate::structs::Diagnostic;
+use crate::utils::{
+    bytes_from_file, bytes_from_string_with, path_from_string, string_from_bytes_with,
+};
 use crate::{iif, AttrIndexSliceOrItem};
 
+// <editor-fold desc="// Constants ...">
+
+/// The size (in bytes) of each entry in a `filelist.cfg` file
+pub const ENTRY_SIZE: usize = 128;
+
+/// The highest file number a `filelist.cfg` file can hold
+pub const MAX_FILE_NUMBER: usize = 4095;
+
+/// The bytes of an entry holding the table's root name, which can be
+/// up to 40 characters long plus its NUL terminator (see `pathname` in
+/// `FINFGRP.C`'s `struct flist`)
+const ROOT_NAME: Range<usize> = 0..41;
+
+/// The bytes of an entry holding the table's description
+const DESCRIPTION: Range<usize> = 41..74;
+
+/// The bytes of an entry holding the table's DataFlex (logical) name, which
+/// is limited to 10 characters in 2.3b (see `struct flist` in `FINFGRP.C`)
+/// but runs to the end of the entry in 3.0+
+fn dataflex_name_bytes(version: &Version) -> Range<usize> {
+    match version {
+        Version::V23B => 74..85,
+        _ => 74..ENTRY_SIZE,
+    }
+}
+
+// </editor-fold desc="// Constants ...">
+
 // <editor-fold desc="// FileListEntry ...">
 
 #[derive(Clone, Debug, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
 /// A single entry within a DataFlex `filelist.cfg` file
 pub struct FileListEntry {
+    #[pyo3(get)]
     /// A table's numeric index within
     /// the DataFlex "database"
     pub file_number: usize,
+    #[pyo3(get)]
     /// A table's on-disk file name
     pub root_name: String,
+    #[pyo3(get)]
     /// The name by which DataFlex references
     /// a given table
     pub dataflex_name: Option<String>,
+    #[pyo3(get)]
     /// A table's human-readable description
     pub description: Option<String>,
 }
@@ -117,14 +159,34 @@ impl FileListEntry {
     // <editor-fold desc="// Public Methods ...">
 
     pub fn from_bytes<T: Into<usize>>(data: &[u8], idx: Option<T>) -> PyResult<FileListEntry> {
+        FileListEntry::from_versioned_bytes(data, idx, &Version::Unknown, &Encoding::default())
+    }
+
+    /// Parse an entry using the layout of the supplied DataFlex version
+    /// (entries of unknown versions are parsed using the 3.0+ layout),
+    /// decoding its names and description using `encoding`
+    pub fn from_versioned_bytes<T: Into<usize>>(
+        data: &[u8],
+        idx: Option<T>,
+        version: &Version,
+        encoding: &Encoding,
+    ) -> PyResult<FileListEntry> {
         let file_number = match idx {
             Some(value) => value.into(),
             None => 0usize,
         };
 
-        let root_name = string_from_bytes(&data[..40], Some(false))?;
-        let dataflex_name = string_from_bytes(&data[41..73], Some(false))?;
-        let description = string_from_bytes(&data[73..], Some(false))?;
+        if data.len() != ENTRY_SIZE {
+            return Err(PyValueError::new_err(format!(
+                "Expected {} bytes but actually got {}",
+                ENTRY_SIZE,
+                data.len()
+            )));
+        }
+
+        let root_name = string_from_field(&data[ROOT_NAME], encoding)?;
+        let description = string_from_field(&data[DESCRIPTION], encoding)?;
+        let dataflex_name = string_from_field(&data[dataflex_name_bytes(version)], encoding)?;
 
         if (file_number == 0usize && !cl_eq(&root_name, "filelist.cfg"))
             || (file_number > 0usize && cl_eq(&root_name, "filelist.cfg"))
@@ -135,6 +197,10 @@ impl FileListEntry {
             )));
         }
 
+        if file_number > 0 && root_name.is_empty() {
+            return Err(PyValueError::new_err("Missing the table's root name!"));
+        }
+
         if file_number > 0 && dataflex_name.is_empty() && description.is_empty() {
             return Err(PyValueError::new_err("Missing one or more field values!"));
         }
@@ -147,6 +213,32 @@ impl FileListEntry {
         })
     }
 
+    /// Encode the entry as a `filelist.cfg` slot
+    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
+        self.to_versioned_bytes(&Version::Unknown, &Encoding::default())
+    }
+
+    /// Encode the entry as a `filelist.cfg` slot using the layout of the
+    /// supplied DataFlex version, encoding its names and description
+    /// using `encoding`
+    pub fn to_versioned_bytes(&self, version: &Version, encoding: &Encoding) -> PyResult<Vec<u8>> {
+        let mut data: Vec<u8> = vec![0u8; ENTRY_SIZE];
+
+        for (range, value) in [
+            (ROOT_NAME, Some(&self.root_name)),
+            (DESCRIPTION, self.description.as_ref()),
+            (dataflex_name_bytes(version), self.dataflex_name.as_ref()),
+        ] {
+            if let Some(value) = value {
+                let length: usize = range.len();
+
+                data[range].copy_from_slice(&bytes_from_string_with(value, length, encoding)?);
+            }
+        }
+
+        Ok(data)
+    }
+
     pub fn is(&self, table: &AttrIndexSliceOrItem<FileListEntry>) -> bool {
         match table {
             AttrIndexSliceOrItem::Slice(_) => false,
@@ -172,32 +264,75 @@ impl FileListEntry {
     // </editor-fold desc="// Public Methods ...">
 }
 
+#[pymethods]
+impl FileListEntry {
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
 // </editor-fold desc="// FileListEntry ...">
 
 // <editor-fold desc="// FileList ...">
 
 #[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+#[pyo3(text_signature = "(filepath: str)")]
 /// A structured representation of DataFlex's `filelist.cfg` file
 pub struct FileList {
-    /// A table's numeric index within
-    /// the DataFlex "database"
-    pub files: Vec<FileListEntry>,
+    /// The list's slots, indexed by DataFlex file number
+    /// (empty slots are `None`)
+    pub entries: Vec<Option<FileListEntry>>,
+    #[pyo3(get)]
+    /// The (non-empty) slots that were skipped
+    /// because they couldn't be parsed
+    pub diagnostics: Vec<Diagnostic>,
+    /// The raw bytes of each slot that couldn't be parsed, keyed by file
+    /// number, so they're written back unchanged (a truncated final slot
+    /// holds fewer than `ENTRY_SIZE` bytes)
+    pub unparsed: BTreeMap<usize, Vec<u8>>,
+    /// The DataFlex version whose entry layout the list uses
+    pub version: Version,
+    /// The encoding of the entries' names and descriptions
+    pub encoding: Encoding,
 }
 
 unsafe impl Send for FileList {}
 
 impl fmt::Display for FileList {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "FileList<tables: {}>", self.files.len())
+        write!(f, "FileList<tables: {}>", self.len())
     }
 }
 
 impl IntoIterator for FileList {
     type Item = FileListEntry;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = std::iter::Flatten<std::vec::IntoIter<Option<Self::Item>>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.files.into_iter()
+        self.entries.into_iter().flatten()
+    }
+}
+
+impl FromIterator<FileListEntry> for FileList {
+    /// Build a list holding each entry in the slot matching its file number
+    fn from_iter<I: IntoIterator<Item = FileListEntry>>(iter: I) -> Self {
+        let mut list = FileList::default();
+
+        for entry in iter {
+            let file_number: usize = entry.file_number;
+
+            list._grow_to(file_number);
+            list.entries[file_number] = Some(entry);
+        }
+
+        list
     }
 }
 
@@ -207,7 +342,7 @@ impl FileList {
     pub(crate) fn _as_pretty_table(&self) -> String {
         let mut table = PrettyTable::new();
 
-        self.files.iter().for_each(|entry| {
+        self.iter().for_each(|entry| {
             table.add_row(PrettyRow::from(vec![PrettyCell::new(
                 entry._as_pretty_table().as_str(),
             )]));
@@ -216,23 +351,119 @@ impl FileList {
         table.to_string()
     }
 
+    /// Raise a `ValueError` for file numbers that can't hold a table
+    fn _validate_number(file_number: usize) -> PyResult<()> {
+        if file_number == 0 || file_number > MAX_FILE_NUMBER {
+            return Err(PyValueError::new_err(format!(
+                "file numbers must be between 1 and {} (got {})",
+                MAX_FILE_NUMBER, file_number
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Get the (non-`filelist.cfg`) entry with the supplied file number
+    fn _entry_mut(&mut self, file_number: usize) -> PyResult<&mut FileListEntry> {
+        FileList::_validate_number(file_number)?;
+
+        self.entries
+            .get_mut(file_number)
+            .and_then(Option::as_mut)
+            .ok_or_else(|| PyKeyError::new_err(file_number.to_string()))
+    }
+
+    /// Add empty slots until the list has one for `file_number`
+    fn _grow_to(&mut self, file_number: usize) {
+        if self.entries.len() <= file_number {
+            self.entries.resize(file_number + 1, None);
+        }
+    }
+
     // </editor-fold desc="// 'Private' Methods ...">
 
     // <editor-fold desc="// Public Methods ...">
 
+    /// Parse the entries in `data`, recording any (non-empty) slots that
+    /// can't be parsed in the list's `diagnostics`
     pub fn from_bytes(data: &[u8]) -> PyResult<FileList> {
-        Ok(FileList {
-            files: data
-                .chunks_exact(128)
-                .enumerate()
-                .map(|(idx, chunk)| FileListEntry::from_bytes(chunk, Some(idx)))
-                .filter(PyResult::is_ok)
-                .map(PyResult::unwrap)
-                .collect::<Vec<FileListEntry>>(),
-        })
+        FileList::from_versioned_bytes(data, Version::Unknown, Encoding::default())
+    }
+
+    /// Parse the entries in `data` using the layout of the supplied
+    /// DataFlex version and the supplied text encoding
+    pub fn from_versioned_bytes(
+        data: &[u8],
+        version: Version,
+        encoding: Encoding,
+    ) -> PyResult<FileList> {
+        let mut list = FileList {
+            version,
+            encoding,
+            ..FileList::default()
+        };
+        let chunks = data.chunks_exact(ENTRY_SIZE);
+        let remainder: &[u8] = chunks.remainder();
+
+        let altname: usize = dataflex_name_bytes(&list.version).start;
+
+        for (idx, chunk) in chunks.enumerate() {
+            // Unused slots aren't worth reporting. DataFlex treats a slot as
+            // unused when the first byte of its DataFlex name is NUL (see
+            // `!fptr->altname[0]` in `FINFGRP.C`), and "clears" a slot by
+            // zeroing the first byte of each of its fields, leaving the rest
+            // of the slot's bytes behind.
+            if idx > 0 && chunk[altname] == 0 {
+                list.entries.push(None);
+                continue;
+            }
+
+            match FileListEntry::from_versioned_bytes(
+                chunk,
+                Some(idx),
+                &list.version,
+                &list.encoding,
+            ) {
+                Ok(entry) => list.entries.push(Some(entry)),
+                Err(error) => {
+                    list.entries.push(None);
+                    list.unparsed.insert(idx, chunk.to_vec());
+                    list.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::FileListSlot,
+                        "",
+                        (idx * ENTRY_SIZE) as u64,
+                        error,
+                    ));
+                }
+            }
+        }
+
+        if !remainder.is_empty() {
+            list.unparsed
+                .insert(data.len() / ENTRY_SIZE, remainder.to_vec());
+            list.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::FileListSlot,
+                "",
+                (data.len() - remainder.len()) as u64,
+                format!("{} trailing bytes", remainder.len()),
+            ));
+        }
+
+        Ok(list)
     }
 
     pub fn from_path<T: AsRef<str>>(filepath: T) -> PyResult<FileList> {
+        FileList::from_versioned_path(filepath, Version::Unknown, Encoding::default())
+    }
+
+    /// Read the `filelist.cfg` file at (or in) `filepath` using the
+    /// entry layout of the supplied DataFlex version and the supplied
+    /// text encoding
+    pub fn from_versioned_path<T: AsRef<str>>(
+        filepath: T,
+        version: Version,
+        encoding: Encoding,
+    ) -> PyResult<FileList> {
         let mut filepath = AsRef::<str>::as_ref(&filepath).to_string();
 
         if !filepath.ends_with("filelist.cfg") {
@@ -247,7 +478,17 @@ impl FileList {
         }
 
         match bytes_from_file(&filepath, None::<u64>, None::<u64>) {
-            Ok(data) => Ok(FileList::from_bytes(&data)?),
+            Ok(data) => {
+                let mut list = FileList::from_versioned_bytes(&data, version, encoding)?;
+
+                list.diagnostics = list
+                    .diagnostics
+                    .into_iter()
+                    .map(|diagnostic| diagnostic._relative_to(&filepath, 0))
+                    .collect();
+
+                Ok(list)
+            }
             Err(_) => Err(PyFileNotFoundError::new_err(format!(
                 "Could not create a usable `FileList` from path '{}'",
                 &filepath
@@ -256,34 +497,40 @@ impl FileList {
     }
 
     pub fn len(&self) -> usize {
-        self.files.len()
+        self.iter().count()
     }
 
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
-    pub fn iter(&self) -> std::slice::Iter<FileListEntry> {
-        self.files.iter()
+    /// Iterate over the list's (used) entries in file number order
+    pub fn iter(&self) -> impl Iterator<Item = &FileListEntry> {
+        self.entries.iter().flatten()
+    }
+
+    /// Iterate over every slot in the list (used or not)
+    /// alongside the file number it holds
+    pub fn slots(&self) -> impl Iterator<Item = (usize, Option<&FileListEntry>)> {
+        self.entries.iter().map(Option::as_ref).enumerate()
+    }
+
+    /// Get the entry with the supplied file number (if its slot is in use)
+    pub fn entry(&self, file_number: usize) -> Option<&FileListEntry> {
+        self.entries.get(file_number).and_then(Option::as_ref)
     }
 
-    pub fn get(
+    pub fn get<'fl>(
         &'fl self,
         table: AttrIndexSliceOrItem<FileListEntry>,
     ) -> Option<&'fl FileListEntry> {
-        let table = if let AttrIndexSliceOrItem::Index(index) = table {
-            let index = iif!(index > -1, index, self.files.len() as isize + index);
+        if let AttrIndexSliceOrItem::Index(index) = table {
+            let index = iif!(index > -1, index, self.entries.len() as isize + index);
 
-            if index < 0 {
-                return None;
-            }
-
-            AttrIndexSliceOrItem::Index(index)
-        } else {
-            table
-        };
+            return iif!(index < 0, None, self.entry(index as usize));
+        }
 
-        self.files.iter().filter(|entry| entry.is(&table)).next()
+        self.iter().find(|entry| entry.is(&table))
     }
 
     pub fn contains(&self, table: AttrIndexSliceOrItem<FileListEntry>) -> bool {
@@ -293,23 +540,524 @@ impl FileList {
         }
     }
 
+    /// The lowest file number whose slot isn't in use (if any)
+    pub fn next_free_number(&self) -> Option<usize> {
+        (1..=MAX_FILE_NUMBER)
+            .find(|number| self.entry(*number).is_none() && !self.unparsed.contains_key(number))
+    }
+
+    /// Add an entry for a table at `file_number` (or the first free file
+    /// number if none is supplied), returning the file number used
+    pub fn add_entry(
+        &mut self,
+        root_name: &str,
+        dataflex_name: &str,
+        description: &str,
+        file_number: Option<usize>,
+    ) -> PyResult<usize> {
+        let file_number: usize = match file_number {
+            Some(number) => number,
+            None => self
+                .next_free_number()
+                .ok_or_else(|| PyIndexError::new_err("the filelist is full"))?,
+        };
+
+        FileList::_validate_number(file_number)?;
+
+        if self.entry(file_number).is_some() || self.unparsed.contains_key(&file_number) {
+            return Err(PyValueError::new_err(format!(
+                "file number {} is already in use",
+                file_number
+            )));
+        }
+
+        if root_name.trim().is_empty() || cl_eq(root_name.trim(), "filelist.cfg") {
+            return Err(PyValueError::new_err(format!(
+                "'{}' isn't a valid root name",
+                root_name
+            )));
+        }
+
+        let entry = FileListEntry {
+            file_number,
+            root_name: root_name.to_string(),
+            dataflex_name: Some(dataflex_name.to_string()),
+            description: Some(description.to_string()),
+        };
+
+        // Make sure the entry can actually be written
+        entry.to_versioned_bytes(&self.version, &self.encoding)?;
+
+        if self.entries.is_empty() {
+            self.entries.push(Some(FileListEntry::default()));
+        }
+
+        self._grow_to(file_number);
+        self.entries[file_number] = Some(entry);
+
+        Ok(file_number)
+    }
+
+    /// Change the DataFlex (logical) name of the entry at `file_number`
+    pub fn set_dataflex_name(&mut self, file_number: usize, dataflex_name: &str) -> PyResult<()> {
+        bytes_from_string_with(
+            dataflex_name,
+            dataflex_name_bytes(&self.version).len(),
+            &self.encoding,
+        )?;
+
+        self._entry_mut(file_number)?.dataflex_name = Some(dataflex_name.to_string());
+
+        Ok(())
+    }
+
+    /// Change the description of the entry at `file_number`
+    pub fn set_description(&mut self, file_number: usize, description: &str) -> PyResult<()> {
+        bytes_from_string_with(description, DESCRIPTION.len(), &self.encoding)?;
+
+        self._entry_mut(file_number)?.description = Some(description.to_string());
+
+        Ok(())
+    }
+
+    /// Remove the entry at `file_number`, leaving its slot empty
+    pub fn clear_slot(&mut self, file_number: usize) -> PyResult<FileListEntry> {
+        FileList::_validate_number(file_number)?;
+
+        self.entries
+            .get_mut(file_number)
+            .and_then(Option::take)
+            .ok_or_else(|| PyKeyError::new_err(file_number.to_string()))
+    }
+
+    /// Encode the list as the contents of a `filelist.cfg` file. Entry 0
+    /// is always `filelist.cfg`, unused slots are zero-filled, slots that
+    /// couldn't be parsed are written back byte-for-byte, and the file
+    /// keeps every slot it was read with.
+    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
+        let size: usize = self
+            .unparsed
+            .iter()
+            .map(|(file_number, raw)| file_number * ENTRY_SIZE + raw.len())
+            .chain([self.entries.len().max(1) * ENTRY_SIZE])
+            .max()
+            .unwrap_or(ENTRY_SIZE);
+        let mut data: Vec<u8> = vec![0u8; size];
+
+        data[..ENTRY_SIZE].copy_from_slice(&FileListEntry::default().to_bytes()?);
+
+        for (file_number, raw) in self.unparsed.iter() {
+            let start: usize = file_number * ENTRY_SIZE;
+
+            data[start..start + raw.len()].copy_from_slice(raw);
+        }
+
+        for (file_number, entry) in self.slots().skip(1) {
+            let entry: &FileListEntry = match entry {
+                Some(entry) => entry,
+                None => continue,
+            };
+
+            FileList::_validate_number(file_number)?;
+
+            let start: usize = file_number * ENTRY_SIZE;
+
+            data[start..start + ENTRY_SIZE]
+                .copy_from_slice(&entry.to_versioned_bytes(&self.version, &self.encoding)?);
+        }
+
+        Ok(data)
+    }
+
+    /// Write the list to the `filelist.cfg` file at (or in) `filepath`
+    pub fn save<T: AsRef<str>>(&self, filepath: T) -> PyResult<()> {
+        let mut filepath: String = filepath.as_ref().to_string();
+
+        if path_from_string(&filepath, Some(false)).is_dir() {
+            filepath = Path::new(&filepath)
+                .join("filelist.cfg")
+                .to_string_lossy()
+                .to_string();
+        }
+
+        fs::write(&filepath, self.to_bytes()?)?;
+
+        Ok(())
+    }
+
     // </editor-fold desc="// Public Methods ...">
 }
 
+#[pymethods]
+impl FileList {
+    #[new]
+    /// Read the `filelist.cfg` file at (or in) `filepath`
+    fn py_new(filepath: &str) -> PyResult<FileList> {
+        FileList::from_path(filepath)
+    }
+
+    #[getter]
+    /// The list's (used) entries in file number order
+    fn get_files(&self) -> Vec<FileListEntry> {
+        self.iter().cloned().collect()
+    }
+
+    #[getter]
+    /// The DataFlex version whose entry layout the list uses
+    fn get_version(&self) -> String {
+        self.version.to_string()
+    }
+
+    #[pyo3(
+        name = "slots",
+        text_signature = "($self) -> List[Optional[FileListEntry]]"
+    )]
+    /// Every slot in the list, indexed by file number
+    /// (empty slots are `None`)
+    fn py_slots(&self) -> Vec<Option<FileListEntry>> {
+        self.entries.clone()
+    }
+
+    #[pyo3(name = "next_free_number", text_signature = "($self) -> Optional[int]")]
+    /// The lowest file number whose slot isn't in use (if any)
+    fn py_next_free_number(&self) -> Option<usize> {
+        self.next_free_number()
+    }
+
+    #[pyo3(
+        name = "add_entry",
+        text_signature = "($self, root_name: str, dataflex_name: str, description: str, file_number: Optional[int] = None) -> int"
+    )]
+    /// Add an entry at `file_number` (or the first free file number)
+    fn py_add_entry(
+        &mut self,
+        root_name: &str,
+        dataflex_name: &str,
+        description: &str,
+        file_number: Option<usize>,
+    ) -> PyResult<usize> {
+        self.add_entry(root_name, dataflex_name, description, file_number)
+    }
+
+    #[pyo3(
+        name = "set_dataflex_name",
+        text_signature = "($self, file_number: int, dataflex_name: str)"
+    )]
+    /// Change the DataFlex (logical) name of the entry at `file_number`
+    fn py_set_dataflex_name(&mut self, file_number: usize, dataflex_name: &str) -> PyResult<()> {
+        self.set_dataflex_name(file_number, dataflex_name)
+    }
+
+    #[pyo3(
+        name = "set_description",
+        text_signature = "($self, file_number: int, description: str)"
+    )]
+    /// Change the description of the entry at `file_number`
+    fn py_set_description(&mut self, file_number: usize, description: &str) -> PyResult<()> {
+        self.set_description(file_number, description)
+    }
+
+    #[pyo3(
+        name = "clear_slot",
+        text_signature = "($self, file_number: int) -> FileListEntry"
+    )]
+    /// Remove the entry at `file_number`, leaving its slot empty
+    fn py_clear_slot(&mut self, file_number: usize) -> PyResult<FileListEntry> {
+        self.clear_slot(file_number)
+    }
+
+    #[pyo3(name = "to_bytes", text_signature = "($self) -> bytes")]
+    /// Encode the list as the contents of a `filelist.cfg` file
+    fn py_to_bytes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        Ok(PyBytes::new(py, &self.to_bytes()?))
+    }
+
+    #[pyo3(name = "save", text_signature = "($self, filepath: str)")]
+    /// Write the list to the `filelist.cfg` file at (or in) `filepath`
+    fn py_save(&self, filepath: &str) -> PyResult<()> {
+        self.save(filepath)
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
 // </editor-fold desc="// FileList ...">
 
+// <editor-fold desc="// Helpers ...">
+
+/// Decode one of an entry's NUL-terminated string fields, ignoring anything
+/// after the terminator (which may be left over from a previous value)
+fn string_from_field(data: &[u8], encoding: &Encoding) -> PyResult<String> {
+    let end: usize = data
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(data.len());
+
+    string_from_bytes_with(&data[..end], false, encoding, &Whitespace::Trim)
+}
+
+// </editor-fold desc="// Helpers ...">
+
 // <editor-fold desc="// Tests ...">
 
 #[cfg(test)]
 mod tests {
     #![allow(unused_imports)]
-    use super::{FileList, FileListEntry};
+    use super::{FileList, FileListEntry, ENTRY_SIZE, MAX_FILE_NUMBER};
+    use crate::enums::{Encoding, Version};
+    use crate::AttrIndexSliceOrItem;
 
     #[test]
     /// Test that the `FileList` structure behaves as expected
     fn gets_file_lists() {
         todo!()
     }
+
+    #[test]
+    /// Test that edited lists survive being written and read back
+    fn writes_file_lists() {
+        let mut list = FileList::default();
+
+        assert_eq!(
+            list.add_entry("CUSTOMER", "CUSTOMER", "Customers", None)
+                .unwrap(),
+            1
+        );
+        assert_eq!(list.add_entry("ORDHEAD", "ORDERS", "", Some(7)).unwrap(), 7);
+        assert_eq!(list.add_entry("VENDOR", "VENDOR", "", None).unwrap(), 2);
+
+        assert!(list.add_entry("DUPE", "DUPE", "", Some(7)).is_err());
+        assert!(list
+            .add_entry("BIG", "BIG", "", Some(MAX_FILE_NUMBER + 1))
+            .is_err());
+        assert!(list.add_entry("filelist.cfg", "", "", None).is_err());
+
+        list.set_dataflex_name(7, "ORDER_HEADERS").unwrap();
+        list.set_description(7, "Order headers").unwrap();
+        list.clear_slot(2).unwrap();
+
+        assert!(list.clear_slot(0).is_err());
+        assert!(list.set_description(2, "Vendors").is_err());
+
+        let data: Vec<u8> = list.to_bytes().unwrap();
+
+        assert_eq!(data.len(), 8 * ENTRY_SIZE);
+
+        let read = FileList::from_bytes(&data).unwrap();
+
+        assert!(read.diagnostics.is_empty());
+        assert_eq!(
+            read.iter()
+                .map(|entry| (entry.file_number, entry.root_name.as_str()))
+                .collect::<Vec<(usize, &str)>>(),
+            vec![(0, "filelist.cfg"), (1, "CUSTOMER"), (7, "ORDHEAD")]
+        );
+        assert_eq!(
+            read.entry(7)
+                .and_then(|entry| entry.dataflex_name.as_deref()),
+            Some("ORDER_HEADERS")
+        );
+        assert_eq!(
+            read.entry(7).and_then(|entry| entry.description.as_deref()),
+            Some("Order headers")
+        );
+    }
+
+    #[test]
+    /// Test that empty slots keep later entries at their file numbers
+    fn keeps_empty_slots() {
+        let mut data: Vec<u8> = vec![0u8; ENTRY_SIZE * 5];
+
+        data[..12].copy_from_slice(b"filelist.cfg");
+        data[384..391].copy_from_slice(b"ORDHEAD");
+        data[384 + 74..384 + 80].copy_from_slice(b"ORDERS");
+
+        let mut list = FileList::from_bytes(&data).unwrap();
+
+        assert_eq!(
+            list.slots()
+                .map(|(number, entry)| (number, entry.map(|e| e.root_name.as_str())))
+                .collect::<Vec<(usize, Option<&str>)>>(),
+            vec![
+                (0, Some("filelist.cfg")),
+                (1, None),
+                (2, None),
+                (3, Some("ORDHEAD")),
+                (4, None),
+            ]
+        );
+        assert_eq!(
+            list.get(AttrIndexSliceOrItem::Index(3))
+                .map(|entry| entry.root_name.as_str()),
+            Some("ORDHEAD")
+        );
+        assert!(list.get(AttrIndexSliceOrItem::Index(1)).is_none());
+        assert_eq!(list.next_free_number(), Some(1));
+
+        list.add_entry("CUSTOMER", "CUSTOMER", "", None).unwrap();
+        list.add_entry("VENDOR", "VENDOR", "", Some(2)).unwrap();
+
+        assert_eq!(list.next_free_number(), Some(4));
+        assert_eq!(list.to_bytes().unwrap(), {
+            let mut expected = data.clone();
+
+            expected[128..136].copy_from_slice(b"CUSTOMER");
+            expected[128 + 74..128 + 82].copy_from_slice(b"CUSTOMER");
+            expected[256..262].copy_from_slice(b"VENDOR");
+            expected[256 + 74..256 + 80].copy_from_slice(b"VENDOR");
+            expected
+        });
+    }
+
+    #[test]
+    /// Test that entries are read and written using the 2.3b and 3.0 layouts
+    fn reads_versioned_layouts() {
+        // 2.3b: `struct flist { pathname[41]; username[33]; altname[11]; }`
+        // with stale bytes left in the unused tail of the slot
+        let mut data: Vec<u8> = vec![0u8; ENTRY_SIZE * 2];
+
+        data[..12].copy_from_slice(b"filelist.cfg");
+        data[128..135].copy_from_slice(b"ORDHEAD");
+        data[128 + 41..128 + 54].copy_from_slice(b"Order headers");
+        data[128 + 74..128 + 84].copy_from_slice(b"ORDER_HEAD");
+        data[128 + 90..128 + 95].copy_from_slice(b"stale");
+
+        let mut list =
+            FileList::from_versioned_bytes(&data, Version::V23B, Encoding::Latin1).unwrap();
+        let entry = list.entry(1).unwrap();
+
+        assert_eq!(entry.root_name, "ORDHEAD");
+        assert_eq!(entry.description.as_deref(), Some("Order headers"));
+        assert_eq!(entry.dataflex_name.as_deref(), Some("ORDER_HEAD"));
+        assert_eq!(list.to_bytes().unwrap()[128..128 + 85], data[128..128 + 85]);
+        assert!(list.set_dataflex_name(1, "ORDER_HEADERS").is_err());
+        assert!(list.add_entry("VENDOR", "VENDOR_LIST", "", None).is_err());
+
+        // 3.0: the first 265 slots (i.e. every non-empty one) of a real
+        // `filelist.cfg`, whose deleted slots (2, 50, 253, 254, 263, and
+        // 264) still hold most of their old bytes
+        let data: &[u8] = include_bytes!("../../test_data/dev_data/filelist.cfg");
+
+        let list = FileList::from_versioned_bytes(data, Version::V30, Encoding::Latin1).unwrap();
+
+        assert!(list.diagnostics.is_empty());
+        assert_eq!(list.entries.len(), 265);
+        assert_eq!(
+            list.iter()
+                .map(|entry| (entry.file_number, entry.root_name.as_str()))
+                .collect::<Vec<(usize, &str)>>(),
+            vec![(0, "filelist.cfg"), (1, "dev_data")]
+        );
+        assert_eq!(
+            list.entry(1)
+                .and_then(|entry| entry.dataflex_name.as_deref()),
+            Some("dev_data")
+        );
+        assert!([2, 50, 253, 254, 263, 264]
+            .iter()
+            .all(|number| list.entry(*number).is_none()));
+        assert_eq!(list.to_bytes().unwrap()[..256], data[..256]);
+        assert_eq!(
+            FileList::from_bytes(&list.to_bytes().unwrap())
+                .unwrap()
+                .entries,
+            list.entries
+        );
+    }
+
+    #[test]
+    /// Test that entries are decoded and encoded using the list's encoding
+    fn uses_list_encodings() {
+        let mut data: Vec<u8> = vec![0u8; ENTRY_SIZE * 2];
+
+        data[..12].copy_from_slice(b"filelist.cfg");
+        data[128..136].copy_from_slice(b"CUSTOMER");
+        data[128 + 41..128 + 45].copy_from_slice(b"Caf\x82");
+        data[128 + 74..128 + 82].copy_from_slice(b"CUSTOMER");
+
+        let mut list =
+            FileList::from_versioned_bytes(&data, Version::V30, Encoding::Cp850).unwrap();
+
+        assert_eq!(list.entry(1).unwrap().description.as_deref(), Some("Café"));
+        assert_eq!(list.to_bytes().unwrap(), data);
+
+        list.set_description(1, "Déjà vu").unwrap();
+
+        assert_eq!(
+            &list.to_bytes().unwrap()[128 + 41..128 + 49],
+            b"D\x82j\x85 vu\0"
+        );
+        assert_eq!(
+            FileList::from_bytes(&data)
+                .unwrap()
+                .entry(1)
+                .unwrap()
+                .description
+                .as_deref(),
+            Some("Caf\u{82}")
+        );
+    }
+
+    #[test]
+    /// Test that unparseable slots are recorded rather than silently dropped
+    fn records_skipped_slots() {
+        // Unparseable slots are described by the (Python) error they raised
+        pyo3::prepare_freethreaded_python();
+
+        let mut data: Vec<u8> = vec![0u8; 128 * 4];
+
+        data[..12].copy_from_slice(b"filelist.cfg");
+        // Slot 1 is a valid entry
+        data[128..136].copy_from_slice(b"CUSTOMER");
+        data[128 + 74..128 + 82].copy_from_slice(b"CUSTOMER");
+        // Slot 2 is empty, slot 3 has a DataFlex name but no root name
+        data[384 + 74..384 + 81].copy_from_slice(b"ORDHEAD");
+        data.extend_from_slice(&[1u8; 5]);
+
+        let mut list = FileList::from_bytes(&data).unwrap();
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(
+            list.diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.offset)
+                .collect::<Vec<u64>>(),
+            vec![384, 512]
+        );
+
+        // Skipped slots are written back unchanged and never reused
+        assert_eq!(list.to_bytes().unwrap(), data);
+        assert!(list.add_entry("VENDOR", "VENDOR", "", Some(3)).is_err());
+        assert_eq!(list.add_entry("VENDOR", "VENDOR", "", None).unwrap(), 2);
+        assert_eq!(list.to_bytes().unwrap()[384..512], data[384..512]);
+        assert_eq!(list.to_bytes().unwrap()[512..], data[512..]);
+    }
+
+    #[test]
+    /// Test that root names can fill their (40-character) field
+    fn fills_root_names() {
+        let mut list = FileList::default();
+        let root_name: String = "R".repeat(40);
+
+        list.add_entry(&root_name, "LONG", "", None).unwrap();
+
+        assert!(list.add_entry(&"R".repeat(41), "LONGER", "", None).is_err());
+        assert_eq!(
+            FileList::from_bytes(&list.to_bytes().unwrap())
+                .unwrap()
+                .entry(1)
+                .map(|entry| entry.root_name.as_str()),
+            Some(root_name.as_str())
+        );
+    }
 }
 
 // </editor-fold desc="// Tests ...">
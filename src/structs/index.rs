@@ -8,24 +8,29 @@ use std::iter::IntoIterator;
 use gluesql::core::data::SchemaIndex; // SchemaIndexOrd
 use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
 use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
 use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 // Crate-Level Imports
-use crate::enums::{IndexCollation, IndexType};
+use crate::enums::{DiagnosticKind, IndexCollation, IndexType};
 use crate::structs::segment::FieldSegment;
+use crate::structs::Diagnostic;
 
 // <editor-fold desc="// Index ...">
 
 #[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
 /// A structured representation of an index's
 /// definition in the header of a DataFlex table file
 pub struct Index {
     /// Denotes the index as a "batch" index
     pub r#type: IndexType,
+    #[pyo3(get)]
     /// The total number of columns whose
     /// data make up the indexed rows
     pub field_count: u8,
+    #[pyo3(get)]
     /// The index's field segments
     pub segments: Vec<FieldSegment>,
     /// Denotes the index's "type"
@@ -140,23 +145,67 @@ impl Index {
         Ok(idx)
     }
 
-    pub fn table_from_bytes(data: &[u8]) -> PyResult<Vec<Index>> {
+    /// Parse the index definitions in `data`, recording any that can't
+    /// be parsed in `diagnostics` (with offsets relative to `data`)
+    pub fn table_from_bytes(
+        data: &[u8],
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> PyResult<Vec<Index>> {
         let chunk_size: usize = match data.len() % 18 != 0 {
             true => 8,
             false => 18,
         };
 
-        Ok(data
-            .chunks_exact(chunk_size)
-            .map(|chunk| Index::from_bytes(chunk))
-            .filter(PyResult::is_ok)
-            .map(PyResult::unwrap)
-            .collect::<Vec<Index>>())
+        let mut indexes: Vec<Index> = Vec::new();
+
+        for (idx, chunk) in data.chunks_exact(chunk_size).enumerate() {
+            // Unused index slots have no fields and aren't worth reporting
+            if chunk[0] & 0x7F == 0 {
+                continue;
+            }
+
+            match Index::from_bytes(chunk) {
+                Ok(index) => indexes.push(index),
+                Err(error) => diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Index,
+                    "",
+                    (idx * chunk_size) as u64,
+                    error,
+                )),
+            }
+        }
+
+        Ok(indexes)
     }
 
     // </editor-fold desc="// Public Methods ...">
 }
 
+#[pymethods]
+impl Index {
+    #[getter]
+    /// Denotes the index as a "batch" or "online" index
+    fn get_type(&self) -> String {
+        self.r#type.to_string()
+    }
+
+    #[getter]
+    /// The index's collation / sort order
+    fn get_collation(&self) -> String {
+        self.collation.to_string()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
 // </editor-fold desc="// Index ...">
 
 // <editor-fold desc="// Tests ...">
@@ -0,0 +1,516 @@
+// Referential integrity checks across the related tables of a DataFlex "database"
+
+// Standard Library Imports
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+// Third-Party Imports
+use gluesql::core::data::{Row, Value};
+use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
+use pyo3::exceptions::{PyIndexError, PyKeyError};
+use pyo3::prelude::*;
+use pyo3::PyResult;
+use serde::{Deserialize, Serialize};
+
+// Crate-Level Imports
+use crate::iif;
+use crate::structs::{DataFlexDB, DataFlexTable, Relationship};
+
+/// A (possibly composite) related value, with one segment per column
+/// relation in the key (`None` segments are null, blank, or zero)
+type Key = Vec<Option<String>>;
+
+// <editor-fold desc="// RelationshipCheck ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+/// The result of checking a single relationship's integrity
+pub struct RelationshipCheck {
+    #[pyo3(get)]
+    /// The column relations making up the (possibly
+    /// composite) key that was checked
+    pub relationships: Vec<Relationship>,
+    #[pyo3(get)]
+    /// The number of child records with a related value
+    pub checked: u64,
+    #[pyo3(get)]
+    /// The numbers of the child records whose related
+    /// key doesn't match any parent record
+    pub orphaned_records: Vec<u64>,
+    #[pyo3(get)]
+    /// The number of parent records with at least one child
+    /// (i.e. those whose deletion could orphan children)
+    pub referenced_parents: u64,
+}
+
+unsafe impl Send for RelationshipCheck {}
+
+impl fmt::Display for RelationshipCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} of {} child records orphaned",
+            key_label(&self.relationships),
+            self.orphaned_records.len(),
+            self.checked
+        )
+    }
+}
+
+#[pymethods]
+impl RelationshipCheck {
+    fn __repr__(&self) -> String {
+        format!("RelationshipCheck<{}>", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+// </editor-fold desc="// RelationshipCheck ...">
+
+// <editor-fold desc="// IntegrityReport ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+/// The results of checking every relationship in a DataFlex "database"
+pub struct IntegrityReport {
+    #[pyo3(get)]
+    /// The per-relationship results
+    pub checks: Vec<RelationshipCheck>,
+}
+
+unsafe impl Send for IntegrityReport {}
+
+impl fmt::Display for IntegrityReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "IntegrityReport<relationships: {} | orphaned records: {}>",
+            self.checks.len(),
+            self.orphan_count()
+        )
+    }
+}
+
+impl IntegrityReport {
+    // <editor-fold desc="// 'Private' Methods ...">
+
+    pub(crate) fn _as_pretty_table(&self) -> String {
+        let mut table = PrettyTable::new();
+
+        table.set_titles(PrettyRow::from(vec![
+            PrettyCell::new("relationship"),
+            PrettyCell::new("checked"),
+            PrettyCell::new("orphaned"),
+            PrettyCell::new("referenced parents"),
+        ]));
+
+        self.checks.iter().for_each(|check| {
+            table.add_row(PrettyRow::from(vec![
+                PrettyCell::new(&key_label(&check.relationships)),
+                PrettyCell::new(&check.checked.to_string()),
+                PrettyCell::new(&check.orphaned_records.len().to_string()),
+                PrettyCell::new(&check.referenced_parents.to_string()),
+            ]));
+        });
+
+        table.to_string()
+    }
+
+    // </editor-fold desc="// 'Private' Methods ...">
+
+    // <editor-fold desc="// Public Methods ...">
+
+    /// Check every relationship in the supplied db
+    pub fn for_db(db: &DataFlexDB) -> PyResult<IntegrityReport> {
+        let mut checks: Vec<RelationshipCheck> = Vec::new();
+
+        for relationships in db.relationships().keys() {
+            let parent_keys: HashMap<Key, u64> = parent_keys(db, &relationships)?;
+            let child_keys: Vec<(u64, Key)> = child_keys(db, &relationships)?;
+
+            let orphaned_records: Vec<u64> = child_keys
+                .iter()
+                .filter(|(_, key)| !parent_keys.contains_key(key))
+                .map(|(record, _)| *record)
+                .collect();
+
+            let related_keys: HashSet<&Key> = child_keys.iter().map(|(_, key)| key).collect();
+
+            let referenced_parents: u64 = parent_keys
+                .iter()
+                .filter(|(key, _)| related_keys.contains(key))
+                .map(|(_, count)| *count)
+                .sum();
+
+            checks.push(RelationshipCheck {
+                checked: child_keys.len() as u64,
+                orphaned_records,
+                referenced_parents,
+                relationships: relationships.into_iter().cloned().collect(),
+            });
+        }
+
+        Ok(IntegrityReport { checks })
+    }
+
+    /// The total number of orphaned child records
+    pub fn orphan_count(&self) -> usize {
+        self.checks
+            .iter()
+            .map(|check| check.orphaned_records.len())
+            .sum()
+    }
+
+    /// Indicates that no orphaned child records were found
+    pub fn is_clean(&self) -> bool {
+        self.orphan_count() == 0
+    }
+
+    // </editor-fold desc="// Public Methods ...">
+}
+
+#[pymethods]
+impl IntegrityReport {
+    #[getter]
+    /// Indicates that no orphaned child records were found
+    fn get_clean(&self) -> bool {
+        self.is_clean()
+    }
+
+    fn __len__(&self) -> usize {
+        self.checks.len()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
+// </editor-fold desc="// IntegrityReport ...">
+
+// <editor-fold desc="// DeleteImpact ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+/// The child records a single relationship would leave
+/// orphaned if a given parent record were deleted
+pub struct DeleteImpact {
+    #[pyo3(get)]
+    /// The column relations making up the (possibly
+    /// composite) key through which the children relate
+    pub relationships: Vec<Relationship>,
+    #[pyo3(get)]
+    /// The numbers of the child records that would be orphaned
+    pub child_records: Vec<u64>,
+}
+
+unsafe impl Send for DeleteImpact {}
+
+impl fmt::Display for DeleteImpact {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} child records would be orphaned",
+            key_label(&self.relationships),
+            self.child_records.len()
+        )
+    }
+}
+
+impl DeleteImpact {
+    // <editor-fold desc="// Public Methods ...">
+
+    /// Find the child records (in every relationship in which `table` is
+    /// the parent) that deleting `record_number` from `table` would orphan.
+    /// Children aren't orphaned if another parent record shares the
+    /// deleted record's (whole) related key.
+    pub fn for_record(
+        db: &DataFlexDB,
+        table: &str,
+        record_number: u64,
+    ) -> PyResult<Vec<DeleteImpact>> {
+        let file_number: usize = match db.file_number_of(table) {
+            Some(file_number) => file_number,
+            None => return Err(PyKeyError::new_err(table.to_string())),
+        };
+
+        let mut impacts: Vec<DeleteImpact> = Vec::new();
+
+        let graph = db.relationships();
+
+        for relationships in graph
+            .keys()
+            .into_iter()
+            .filter(|relationships| relationships[0].parent_file == file_number)
+        {
+            let parent: &DataFlexTable = related_table(db, file_number)?;
+
+            let row: Row = match parent._read_record(record_number)? {
+                Some(row) => row,
+                None => {
+                    return Err(PyIndexError::new_err(format!(
+                        "record {} of '{}' is deleted",
+                        record_number, table
+                    )))
+                }
+            };
+
+            let key: Key = match parent_columns(parent, &relationships) {
+                Some(columns) => match row_key(&row, record_number, &columns) {
+                    Some(key) => key,
+                    None => continue,
+                },
+                None => continue,
+            };
+
+            if parent_keys(db, &relationships)?
+                .get(&key)
+                .copied()
+                .unwrap_or(0)
+                > 1
+            {
+                continue;
+            }
+
+            let child_records: Vec<u64> = child_keys(db, &relationships)?
+                .into_iter()
+                .filter(|(_, child)| *child == key)
+                .map(|(record, _)| record)
+                .collect();
+
+            if !child_records.is_empty() {
+                impacts.push(DeleteImpact {
+                    relationships: relationships.into_iter().cloned().collect(),
+                    child_records,
+                });
+            }
+        }
+
+        Ok(impacts)
+    }
+
+    // </editor-fold desc="// Public Methods ...">
+}
+
+#[pymethods]
+impl DeleteImpact {
+    fn __repr__(&self) -> String {
+        format!("DeleteImpact<{}>", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+// </editor-fold desc="// DeleteImpact ...">
+
+// <editor-fold desc="// Helpers ...">
+
+/// Reduce a related value to a comparable key. Null, blank, and zero
+/// values are how DataFlex records an *absent* relation, so they
+/// produce no key at all.
+fn value_key(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::I64(0) => None,
+        Value::I64(number) => Some(number.to_string()),
+        Value::F64(number) if *number == 0.0 => None,
+        Value::F64(number) if number.fract() == 0.0 => Some((*number as i64).to_string()),
+        Value::F64(number) => Some(number.to_string()),
+        Value::Str(text) if text.trim().is_empty() => None,
+        Value::Str(text) => Some(text.trim().to_string()),
+        other => Some(format!("{:?}", other)),
+    }
+}
+
+/// Reduce the related columns of a record to a comparable (composite)
+/// key. Records whose every segment is absent don't relate to anything,
+/// so they produce no key at all.
+fn row_key(row: &Row, record_number: u64, columns: &[Option<usize>]) -> Option<Key> {
+    let key: Key = columns
+        .iter()
+        .map(|column| match column {
+            Some(idx) => row.0.get(*idx).and_then(value_key),
+            None => Some(record_number.to_string()),
+        })
+        .collect();
+
+    iif!(key.iter().all(Option::is_none), None, Some(key))
+}
+
+/// Label a (possibly composite) key by its column relations
+/// (e.g. `ORDLINE.(ORDER_NO, LINE_NO) -> ORDHEAD.(ORDER_NO, LINE_NO)`)
+fn key_label(relationships: &[Relationship]) -> String {
+    match relationships {
+        [] => String::new(),
+        [relationship] => relationship.to_string(),
+        [first, ..] => {
+            let columns = |column: fn(&Relationship) -> &String| {
+                relationships
+                    .iter()
+                    .map(|relationship| column(relationship).as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            };
+
+            format!(
+                "{}.({}) -> {}.({})",
+                first.child_table,
+                columns(|relationship| &relationship.child_column),
+                first.parent_table,
+                columns(|relationship| &relationship.parent_column),
+            )
+        }
+    }
+}
+
+/// Get a relationship's parent or child table by file number
+fn related_table(db: &DataFlexDB, file_number: usize) -> PyResult<&DataFlexTable> {
+    db.table_by_number(file_number)
+        .ok_or_else(|| PyKeyError::new_err(file_number.to_string()))
+}
+
+/// The positions of a key's parent columns in the parent's records
+/// (`None` for relations to the record number itself), or `None` if
+/// any of the parent columns don't exist
+fn parent_columns(
+    parent: &DataFlexTable,
+    relationships: &[&Relationship],
+) -> Option<Vec<Option<usize>>> {
+    relationships
+        .iter()
+        .map(|relationship| {
+            if relationship.parent_column == "RECNUM" {
+                return Some(None);
+            }
+
+            parent
+                .header
+                .columns
+                .iter()
+                .position(|column| column.name == relationship.parent_column)
+                .map(Some)
+        })
+        .collect()
+}
+
+/// Count the (non-deleted) parent records for each related key
+fn parent_keys(db: &DataFlexDB, relationships: &[&Relationship]) -> PyResult<HashMap<Key, u64>> {
+    let parent: &DataFlexTable = related_table(db, relationships[0].parent_file)?;
+    let mut keys: HashMap<Key, u64> = HashMap::new();
+
+    // Children can't relate to columns that don't exist
+    let columns: Vec<Option<usize>> = match parent_columns(parent, relationships) {
+        Some(columns) => columns,
+        None => return Ok(keys),
+    };
+
+    for record_number in 1..=parent.header.record_count {
+        let row: Row = match parent._read_record(record_number)? {
+            Some(row) => row,
+            None => continue,
+        };
+
+        if let Some(key) = row_key(&row, record_number, &columns) {
+            *keys.entry(key).or_insert(0) += 1;
+        }
+    }
+
+    Ok(keys)
+}
+
+/// Get the record number and related key of every (non-deleted)
+/// child record that actually has a related key
+fn child_keys(db: &DataFlexDB, relationships: &[&Relationship]) -> PyResult<Vec<(u64, Key)>> {
+    let child: &DataFlexTable = related_table(db, relationships[0].child_file)?;
+
+    let columns: Vec<Option<usize>> = match relationships
+        .iter()
+        .map(|relationship| {
+            child
+                .header
+                .columns
+                .iter()
+                .position(|column| column.name == relationship.child_column)
+                .map(Some)
+        })
+        .collect::<Option<Vec<Option<usize>>>>()
+    {
+        Some(columns) => columns,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut keys: Vec<(u64, Key)> = Vec::new();
+
+    for record_number in 1..=child.header.record_count {
+        if let Some(row) = child._read_record(record_number)? {
+            if let Some(key) = row_key(&row, record_number, &columns) {
+                keys.push((record_number, key));
+            }
+        }
+    }
+
+    Ok(keys)
+}
+
+// </editor-fold desc="// Helpers ...">
+
+// <editor-fold desc="// Tests ...">
+
+#[cfg(test)]
+mod tests {
+    use gluesql::core::data::{Row, Value};
+
+    use super::{row_key, value_key};
+
+    #[test]
+    /// Test that related values are reduced to comparable keys
+    fn keys_related_values() {
+        assert_eq!(value_key(&Value::I64(42)), Some("42".to_string()));
+        assert_eq!(value_key(&Value::F64(42.0)), value_key(&Value::I64(42)));
+        assert_eq!(
+            value_key(&Value::Str("ACME  ".to_string())),
+            Some("ACME".to_string())
+        );
+        assert_eq!(value_key(&Value::I64(0)), None);
+        assert_eq!(value_key(&Value::Str("   ".to_string())), None);
+        assert_eq!(value_key(&Value::Null), None);
+    }
+
+    #[test]
+    /// Test that composite keys keep every segment and
+    /// are only absent when every segment is
+    fn keys_composite_values() {
+        let row = Row(vec![
+            Value::I64(42),
+            Value::I64(0),
+            Value::Str("A".to_string()),
+        ]);
+
+        assert_eq!(
+            row_key(&row, 7, &[Some(0), Some(2)]),
+            Some(vec![Some("42".to_string()), Some("A".to_string())])
+        );
+        assert_eq!(
+            row_key(&row, 7, &[Some(1), None]),
+            Some(vec![None, Some("7".to_string())])
+        );
+        assert_eq!(row_key(&row, 7, &[Some(1)]), None);
+        assert_ne!(
+            row_key(&row, 7, &[Some(0), Some(2)]),
+            row_key(&row, 7, &[Some(0), Some(1)])
+        );
+    }
+}
+
+// </editor-fold desc="// Tests ...">
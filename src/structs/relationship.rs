@@ -0,0 +1,636 @@
+// A structured representation of the relationships between the tables in a DataFlex "database"
+
+// Standard Library Imports
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+// Third-Party Imports
+use caseless::compatibility_caseless_match_str as cl_eq;
+use gluesql::core::ast::{ColumnDef, DataType as SqlDataType};
+use gluesql::core::data::{Row, Schema, Value};
+use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// Crate-Level Imports
+use crate::iif;
+
+// <editor-fold desc="// Constants ...">
+
+/// The name of the virtual table through which a db's
+/// relationships are exposed to SQL as foreign keys
+pub const FOREIGN_KEYS_TABLE: &str = "FOREIGN_KEYS";
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// Relationship ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+/// A single column-level relation from a "child" table
+/// to the "parent" table whose column it refers to
+pub struct Relationship {
+    #[pyo3(get)]
+    /// The child table's DataFlex file number
+    pub child_file: usize,
+    #[pyo3(get)]
+    /// The child table's root name
+    pub child_table: String,
+    #[pyo3(get)]
+    /// The name of the child table's relating column
+    pub child_column: String,
+    #[pyo3(get)]
+    /// The parent table's DataFlex file number
+    pub parent_file: usize,
+    #[pyo3(get)]
+    /// The parent table's root name
+    pub parent_table: String,
+    #[pyo3(get)]
+    /// The name of the parent table's related column
+    pub parent_column: String,
+}
+
+unsafe impl Send for Relationship {}
+
+impl fmt::Display for Relationship {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}.{} -> {}.{}",
+            self.child_table, self.child_column, self.parent_table, self.parent_column
+        )
+    }
+}
+
+#[pymethods]
+impl Relationship {
+    fn __repr__(&self) -> String {
+        format!("Relationship<{}>", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+// </editor-fold desc="// Relationship ...">
+
+// <editor-fold desc="// RelationshipGraph ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+/// The graph of parent / child relationships between
+/// the tables in a DataFlex "database"
+pub struct RelationshipGraph {
+    #[pyo3(get)]
+    /// The root names of every (loaded) table in the
+    /// db, in file number order
+    pub tables: Vec<String>,
+    #[pyo3(get)]
+    /// The graph's edges (i.e. its column-level relations)
+    pub relationships: Vec<Relationship>,
+}
+
+unsafe impl Send for RelationshipGraph {}
+
+impl fmt::Display for RelationshipGraph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "RelationshipGraph<tables: {} | relationships: {}>",
+            self.tables.len(),
+            self.relationships.len()
+        )
+    }
+}
+
+impl RelationshipGraph {
+    // <editor-fold desc="// 'Private' Methods ...">
+
+    pub(crate) fn _as_pretty_table(&self) -> String {
+        let mut table = PrettyTable::new();
+
+        table.set_titles(PrettyRow::from(vec![
+            PrettyCell::new("child"),
+            PrettyCell::new("column"),
+            PrettyCell::new("parent"),
+            PrettyCell::new("column"),
+        ]));
+
+        self.relationships.iter().for_each(|relation| {
+            table.add_row(PrettyRow::from(vec![
+                PrettyCell::new(&relation.child_table),
+                PrettyCell::new(&relation.child_column),
+                PrettyCell::new(&relation.parent_table),
+                PrettyCell::new(&relation.parent_column),
+            ]));
+        });
+
+        table.to_string()
+    }
+
+    // </editor-fold desc="// 'Private' Methods ...">
+
+    // <editor-fold desc="// Public Methods ...">
+
+    /// Group the graph's (column-level) relations into keys, each holding
+    /// every relation from one child table to one parent table. DataFlex
+    /// relates a child to its parent through *all* of the child's columns
+    /// that point at the parent's file, so each group is a single
+    /// (possibly composite) key.
+    pub fn keys(&self) -> Vec<Vec<&Relationship>> {
+        let mut keys: Vec<Vec<&Relationship>> = Vec::new();
+
+        for relation in self.relationships.iter() {
+            match keys.iter_mut().find(|key| {
+                key[0].child_file == relation.child_file
+                    && key[0].parent_file == relation.parent_file
+            }) {
+                Some(key) => key.push(relation),
+                None => keys.push(vec![relation]),
+            }
+        }
+
+        keys
+    }
+
+    /// The relations in which the named table is the child
+    pub fn parents_of(&self, table: &str) -> Vec<&Relationship> {
+        self.relationships
+            .iter()
+            .filter(|relation| cl_eq(&relation.child_table, table))
+            .collect()
+    }
+
+    /// The relations in which the named table is the parent
+    pub fn children_of(&self, table: &str) -> Vec<&Relationship> {
+        self.relationships
+            .iter()
+            .filter(|relation| cl_eq(&relation.parent_table, table))
+            .collect()
+    }
+
+    /// Render the graph as a GraphViz DOT digraph (edges point
+    /// from each child table to its parent)
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph relationships {\n    node [shape=box];\n");
+
+        self.tables
+            .iter()
+            .for_each(|table| dot.push_str(&format!("    {};\n", dot_id(table))));
+
+        self.relationships.iter().for_each(|relation| {
+            dot.push_str(&format!(
+                "    {} -> {} [label={}];\n",
+                dot_id(&relation.child_table),
+                dot_id(&relation.parent_table),
+                dot_id(&format!(
+                    "{} -> {}",
+                    relation.child_column, relation.parent_column
+                )),
+            ))
+        });
+
+        dot.push('}');
+        dot.push('\n');
+
+        dot
+    }
+
+    /// Render the graph as a Mermaid entity-relationship diagram
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("erDiagram\n");
+
+        self.relationships.iter().for_each(|relation| {
+            mermaid.push_str(&format!(
+                "    {} ||--o{{ {} : \"{}\"\n",
+                mermaid_id(&relation.parent_table),
+                mermaid_id(&relation.child_table),
+                relation.child_column.replace('"', "'"),
+            ))
+        });
+
+        mermaid
+    }
+
+    /// Find the shortest chain of keys (followed in either direction)
+    /// linking the tables with the supplied file numbers. Each step joins
+    /// on every column relation between its two tables. Keys including a
+    /// relation to a parent's record number, and keys from a table to
+    /// itself, can't be expressed as SQL joins and are never followed.
+    pub fn join_path(&self, from_file: usize, to_file: usize) -> Option<JoinPath> {
+        let joinable: Vec<Vec<&Relationship>> = self
+            .keys()
+            .into_iter()
+            .filter(|key| {
+                key.iter().all(|relation| {
+                    relation.parent_column != "RECNUM"
+                        && relation.child_file != relation.parent_file
+                })
+            })
+            .collect();
+
+        // Breadth-first search, remembering the key
+        // through which each file was first reached
+        let mut reached_by: HashMap<usize, Option<&Vec<&Relationship>>> = HashMap::new();
+        let mut queue: VecDeque<usize> = VecDeque::new();
+
+        reached_by.insert(from_file, None);
+        queue.push_back(from_file);
+
+        while let Some(file) = queue.pop_front() {
+            if file == to_file {
+                break;
+            }
+
+            for key in joinable.iter() {
+                let relation: &Relationship = key[0];
+                let next: usize = if relation.child_file == file {
+                    relation.parent_file
+                } else if relation.parent_file == file {
+                    relation.child_file
+                } else {
+                    continue;
+                };
+
+                if let Entry::Vacant(slot) = reached_by.entry(next) {
+                    slot.insert(Some(key));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        reached_by.get(&to_file)?;
+
+        let mut relationships: Vec<Vec<Relationship>> = Vec::new();
+        let mut file: usize = to_file;
+
+        while let Some(Some(key)) = reached_by.get(&file) {
+            let relation: &Relationship = key[0];
+
+            relationships.insert(0, key.iter().map(|relation| (*relation).clone()).collect());
+            file = iif!(
+                relation.child_file == file,
+                relation.parent_file,
+                relation.child_file
+            );
+        }
+
+        Some(JoinPath::new(from_file, relationships))
+    }
+
+    /// The schema of the virtual `FOREIGN_KEYS` table
+    pub fn schema() -> Schema {
+        let column = |name: &str, data_type: SqlDataType| ColumnDef {
+            name: name.to_string(),
+            data_type,
+            options: Vec::new(),
+        };
+
+        Schema {
+            table_name: FOREIGN_KEYS_TABLE.to_string(),
+            column_defs: vec![
+                column("CHILD_FILE", SqlDataType::Int),
+                column("CHILD_TABLE", SqlDataType::Text),
+                column("CHILD_COLUMN", SqlDataType::Text),
+                column("PARENT_FILE", SqlDataType::Int),
+                column("PARENT_TABLE", SqlDataType::Text),
+                column("PARENT_COLUMN", SqlDataType::Text),
+            ],
+            indexes: Vec::new(),
+        }
+    }
+
+    /// The graph's relations as rows of the virtual `FOREIGN_KEYS` table
+    pub fn rows(&self) -> Vec<Row> {
+        self.relationships
+            .iter()
+            .map(|relation| {
+                Row(vec![
+                    Value::I64(relation.child_file as i64),
+                    Value::Str(relation.child_table.clone()),
+                    Value::Str(relation.child_column.clone()),
+                    Value::I64(relation.parent_file as i64),
+                    Value::Str(relation.parent_table.clone()),
+                    Value::Str(relation.parent_column.clone()),
+                ])
+            })
+            .collect()
+    }
+
+    // </editor-fold desc="// Public Methods ...">
+}
+
+#[pymethods]
+impl RelationshipGraph {
+    #[pyo3(text_signature = "($self, table: str) -> List[Relationship]")]
+    /// The relations in which the named table is the child
+    fn parents(&self, table: &str) -> Vec<Relationship> {
+        self.parents_of(table).into_iter().cloned().collect()
+    }
+
+    #[pyo3(text_signature = "($self, table: str) -> List[Relationship]")]
+    /// The relations in which the named table is the parent
+    fn children(&self, table: &str) -> Vec<Relationship> {
+        self.children_of(table).into_iter().cloned().collect()
+    }
+
+    #[pyo3(name = "to_dot", text_signature = "($self) -> str")]
+    /// Render the graph as a GraphViz DOT digraph
+    fn py_to_dot(&self) -> String {
+        self.to_dot()
+    }
+
+    #[pyo3(name = "to_mermaid", text_signature = "($self) -> str")]
+    /// Render the graph as a Mermaid entity-relationship diagram
+    fn py_to_mermaid(&self) -> String {
+        self.to_mermaid()
+    }
+
+    fn __len__(&self) -> usize {
+        self.relationships.len()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
+// </editor-fold desc="// RelationshipGraph ...">
+
+// <editor-fold desc="// JoinPath ...">
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+/// A chain of relations linking two tables, along with
+/// the SQL needed to join them (a la DataFlex's `RELATE`)
+pub struct JoinPath {
+    #[pyo3(get)]
+    /// The root names of the joined tables, in join order
+    pub tables: Vec<String>,
+    #[pyo3(get)]
+    /// The column relations joined on at each step of
+    /// the path from the first table to the last
+    pub relationships: Vec<Vec<Relationship>>,
+}
+
+unsafe impl Send for JoinPath {}
+
+impl fmt::Display for JoinPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_sql())
+    }
+}
+
+impl JoinPath {
+    // <editor-fold desc="// Public Methods ...">
+
+    /// Build the join path that starts at `from_file` and follows the
+    /// supplied steps (in order), each holding every column relation
+    /// between the step's two tables
+    pub fn new(from_file: usize, relationships: Vec<Vec<Relationship>>) -> JoinPath {
+        let mut file: usize = from_file;
+        let mut tables: Vec<String> = Vec::new();
+
+        for relation in relationships.iter().filter_map(|step| step.first()) {
+            let (current, next, next_file) = iif!(
+                relation.child_file == file,
+                (
+                    &relation.child_table,
+                    &relation.parent_table,
+                    relation.parent_file
+                ),
+                (
+                    &relation.parent_table,
+                    &relation.child_table,
+                    relation.child_file
+                )
+            );
+
+            if tables.is_empty() {
+                tables.push(current.clone());
+            }
+
+            tables.push(next.clone());
+            file = next_file;
+        }
+
+        JoinPath {
+            tables,
+            relationships,
+        }
+    }
+
+    /// The `ON` condition for each of the path's joins
+    /// (ANDing together each of the step's column relations)
+    pub fn conditions(&self) -> Vec<String> {
+        self.relationships
+            .iter()
+            .map(|step| {
+                step.iter()
+                    .map(|relation| {
+                        format!(
+                            "{}.{} = {}.{}",
+                            relation.child_table,
+                            relation.child_column,
+                            relation.parent_table,
+                            relation.parent_column
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" AND ")
+            })
+            .collect()
+    }
+
+    /// The path as the body of a SQL `FROM` clause
+    /// (e.g. `ORDHEAD INNER JOIN CUSTOMER ON ...`)
+    pub fn to_sql(&self) -> String {
+        let mut sql: String = self.tables.first().cloned().unwrap_or_default();
+
+        self.tables
+            .iter()
+            .skip(1)
+            .zip(self.conditions())
+            .for_each(|(table, condition)| {
+                sql.push_str(&format!(" INNER JOIN {} ON {}", table, condition))
+            });
+
+        sql
+    }
+
+    // </editor-fold desc="// Public Methods ...">
+}
+
+#[pymethods]
+impl JoinPath {
+    #[getter]
+    /// The `ON` condition for each of the path's joins
+    fn get_conditions(&self) -> Vec<String> {
+        self.conditions()
+    }
+
+    #[getter]
+    /// The path as the body of a SQL `FROM` clause
+    fn get_sql(&self) -> String {
+        self.to_sql()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("JoinPath<{}>", self.tables.join(" -> "))
+    }
+
+    fn __str__(&self) -> String {
+        self.to_sql()
+    }
+}
+
+// </editor-fold desc="// JoinPath ...">
+
+// <editor-fold desc="// Helpers ...">
+
+/// Quote a name for use as a GraphViz DOT identifier
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Reduce a name to the characters Mermaid allows in entity names
+fn mermaid_id(name: &str) -> String {
+    name.chars()
+        .map(|chr| iif!(chr.is_ascii_alphanumeric() || chr == '-', chr, '_'))
+        .collect()
+}
+
+// </editor-fold desc="// Helpers ...">
+
+// <editor-fold desc="// Tests ...">
+
+#[cfg(test)]
+mod tests {
+    use super::{Relationship, RelationshipGraph};
+
+    #[test]
+    /// Test that `RelationshipGraph`s are rendered as expected
+    fn renders_graphs() {
+        let graph = RelationshipGraph {
+            tables: vec!["CUSTOMER".to_string(), "ORDHEAD".to_string()],
+            relationships: vec![Relationship {
+                child_file: 7,
+                child_table: "ORDHEAD".to_string(),
+                child_column: "CUST_NO".to_string(),
+                parent_file: 2,
+                parent_table: "CUSTOMER".to_string(),
+                parent_column: "NUMBER".to_string(),
+            }],
+        };
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph relationships {\n    node [shape=box];\n    \"CUSTOMER\";\n    \"ORDHEAD\";\n    \"ORDHEAD\" -> \"CUSTOMER\" [label=\"CUST_NO -> NUMBER\"];\n}\n"
+        );
+        assert_eq!(
+            graph.to_mermaid(),
+            "erDiagram\n    CUSTOMER ||--o{ ORDHEAD : \"CUST_NO\"\n"
+        );
+        assert_eq!(graph.parents_of("ordhead").len(), 1);
+        assert!(graph.children_of("ORDHEAD").is_empty());
+    }
+
+    #[test]
+    /// Test that relations between the same two tables form one key
+    fn groups_composite_keys() {
+        let relation = |child: (usize, &str), parent: (usize, &str)| Relationship {
+            child_file: child.0,
+            child_table: "ORDLINE".to_string(),
+            child_column: child.1.to_string(),
+            parent_file: parent.0,
+            parent_table: "PARENT".to_string(),
+            parent_column: parent.1.to_string(),
+        };
+
+        let graph = RelationshipGraph {
+            tables: Vec::new(),
+            relationships: vec![
+                relation((8, "ORDER_NO"), (7, "ORDER_NO")),
+                relation((8, "ITEM"), (3, "NUMBER")),
+                relation((8, "LINE_NO"), (7, "LINE_NO")),
+            ],
+        };
+
+        assert_eq!(
+            graph
+                .keys()
+                .iter()
+                .map(|key| key
+                    .iter()
+                    .map(|relation| relation.child_column.as_str())
+                    .collect::<Vec<&str>>())
+                .collect::<Vec<Vec<&str>>>(),
+            vec![vec!["ORDER_NO", "LINE_NO"], vec!["ITEM"]]
+        );
+    }
+
+    #[test]
+    /// Test that the shortest join path between two tables is found
+    fn finds_join_paths() {
+        let relation = |child: (usize, &str, &str), parent: (usize, &str, &str)| Relationship {
+            child_file: child.0,
+            child_table: child.1.to_string(),
+            child_column: child.2.to_string(),
+            parent_file: parent.0,
+            parent_table: parent.1.to_string(),
+            parent_column: parent.2.to_string(),
+        };
+
+        let graph = RelationshipGraph {
+            tables: vec![
+                "CUSTOMER".to_string(),
+                "ORDHEAD".to_string(),
+                "ORDLINE".to_string(),
+            ],
+            relationships: vec![
+                relation((7, "ORDHEAD", "CUST_NO"), (2, "CUSTOMER", "NUMBER")),
+                relation((8, "ORDLINE", "ORDER_NO"), (7, "ORDHEAD", "ORDER_NO")),
+                relation((8, "ORDLINE", "LINE_NO"), (8, "ORDLINE", "PARENT_LINE")),
+                relation((9, "SHIPMENT", "ORDER_NO"), (8, "ORDLINE", "ORDER_NO")),
+                relation((9, "SHIPMENT", "LINE_NO"), (8, "ORDLINE", "LINE_NO")),
+            ],
+        };
+
+        let path = graph.join_path(2, 8).unwrap();
+
+        assert_eq!(path.tables, vec!["CUSTOMER", "ORDHEAD", "ORDLINE"]);
+        assert_eq!(
+            path.to_sql(),
+            "CUSTOMER INNER JOIN ORDHEAD ON ORDHEAD.CUST_NO = CUSTOMER.NUMBER \
+             INNER JOIN ORDLINE ON ORDLINE.ORDER_NO = ORDHEAD.ORDER_NO"
+        );
+
+        assert_eq!(
+            graph.join_path(8, 7).unwrap().tables,
+            vec!["ORDLINE", "ORDHEAD"]
+        );
+        assert!(graph.join_path(2, 10).is_none());
+
+        // Composite keys are joined on in a single step
+        let path = graph.join_path(7, 9).unwrap();
+
+        assert_eq!(path.tables, vec!["ORDHEAD", "ORDLINE", "SHIPMENT"]);
+        assert_eq!(path.relationships[1].len(), 2);
+        assert_eq!(
+            path.to_sql(),
+            "ORDHEAD INNER JOIN ORDLINE ON ORDLINE.ORDER_NO = ORDHEAD.ORDER_NO \
+             INNER JOIN SHIPMENT ON SHIPMENT.ORDER_NO = ORDLINE.ORDER_NO \
+             AND SHIPMENT.LINE_NO = ORDLINE.LINE_NO"
+        );
+    }
+}
+
+// </editor-fold desc="// Tests ...">
@@ -5,19 +5,23 @@ use std::fmt;
 
 // Third-Party Imports
 use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
+use pyo3::prelude::*;
 use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 // <editor-fold desc="// Field Segment ...">
 
 #[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
 /// A structured representation of a field segment's
 /// definition in the header of a DataFlex table file
 pub struct FieldSegment {
+    #[pyo3(get)]
     /// The column number (with respect to
     /// the column's parent table) to which
     /// the segment refers
     pub column: u8,
+    #[pyo3(get)]
     /// The segment's position within its
     /// associated index
     pub segment: u8,
@@ -70,6 +74,19 @@ impl FieldSegment {
     // </editor-fold desc="// Public Methods ...">
 }
 
+#[pymethods]
+impl FieldSegment {
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
 // </editor-fold desc="// Field Segment ...">
 
 // <editor-fold desc="// Tests ...">
@@ -11,56 +11,72 @@ use gluesql::core::ast::ColumnDef;
 // use gluesql::core::ast::ColumnDef;
 use gluesql::core::data::Schema;
 use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
+use pyo3::prelude::*;
 use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 // Crate-Level Imports
-use crate::enums::{CompressionType, Version};
+use crate::enums::{CompressionType, DiagnosticKind, Encoding, Version};
 use crate::exceptions::{InternalError, NotSupportedError};
-use crate::structs::{Column, Index, TagFile};
+use crate::options::OpenOptions;
+use crate::structs::{Column, Diagnostic, Index, TableDefinition, TagFile};
 use crate::utils::{bytes_from_file, path_from_string, string_from_bytes};
 
 // <editor-fold desc="// Header ...">
 
 #[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+#[pyo3(text_signature = "(filepath: str)")]
 /// A structured representation of the header portion
 /// of a DataFlex table file
 pub struct Header {
     // Common Attributes
+    #[pyo3(get)]
     /// The total number of columns in the table
     pub field_count: u64,
+    #[pyo3(get)]
     /// The total number of records currently
     /// present in the table
     pub record_count: u64,
+    #[pyo3(get)]
     /// The total length (in bytes) of the data
     /// that makes up one "row" in the table
     pub record_length: u64,
+    #[pyo3(get)]
     /// The absolute maximum number of records
     /// that the table should be allowed to store
     pub max_record_count: u64,
+    #[pyo3(get)]
     /// The absolute maximum number of records
     /// that the table has *ever* stored
     pub highest_record_count: u64,
+    #[pyo3(get)]
     /// Indicates that the bytes occupied by
     /// records deleted from the table should
     /// be overwritten with null bytes instead
     /// of being "snipped" from the table
     pub reuse_deleted_space: bool,
+    #[pyo3(get)]
     /// Indicates that the table is configured
     /// for simultaneous access by multiple users
     pub multiuser_reread_active: bool,
     // Embedded Structures
+    #[pyo3(get)]
     /// The header's "index table"
     pub indexes: Vec<Index>,
+    #[pyo3(get)]
     /// The name of the on-disk table file to
     /// which the header belongs
     pub file_root_name: String,
+    #[pyo3(get)]
     /// The header's "column" table
     pub columns: Vec<Column>,
     // Computed Attributes
+    #[pyo3(get)]
     /// The total number of records present
     /// within a given "block" of bytes
     pub records_per_block: u64,
+    #[pyo3(get)]
     /// The total number of "filler" bytes
     /// that should be expected at the end
     /// of every "block" of records
@@ -68,9 +84,23 @@ pub struct Header {
     /// The version of DataFlex in use when
     /// the table was initially created
     pub version: Version,
+    #[pyo3(get)]
     /// The absolute path of the table's
     /// on-disk file
     pub filepath: String,
+    #[pyo3(get)]
+    /// The indexes and columns that were skipped
+    /// because they couldn't be parsed
+    pub diagnostics: Vec<Diagnostic>,
+    #[pyo3(get)]
+    /// The path of the tag file the table's
+    /// column names were read from (if any)
+    pub tag_path: Option<String>,
+    #[pyo3(get)]
+    /// The path of the `.FD` / `.DEF` file the
+    /// table's column names were read from
+    /// (if it has no tag file)
+    pub definition_path: Option<String>,
     // DataFlex 3.0+ Attributes
     /// Denotes the type of compression used
     /// to shrink the table's on-disk size
@@ -336,7 +366,15 @@ impl Header {
         let column_names: Vec<String> =
             TagFile::generate_column_names(field_count, Some(column_names))?;
 
-        Ok(match header_data.len() {
+        let filepath: String = match filepath {
+            Some(file) => file.as_ref().to_string(),
+            None => String::new(),
+        };
+
+        let mut index_diagnostics: Vec<Diagnostic> = Vec::new();
+        let mut column_diagnostics: Vec<Diagnostic> = Vec::new();
+
+        let mut header = match header_data.len() {
             512usize => {
                 Header {
                     // Common Attributes
@@ -348,14 +386,20 @@ impl Header {
                     reuse_deleted_space: header_data[0x58] == 0,
                     multiuser_reread_active: !matches!(header_data[0x5C], 0),
                     // Embedded Structures
-                    indexes: Index::table_from_bytes(header_data[0x64..0xB4].into())?,
+                    indexes: Index::table_from_bytes(
+                        &header_data[0x64..0xB4],
+                        &mut index_diagnostics,
+                    )?,
                     file_root_name: string_from_bytes(
                         &header_data[0xB4..0xBD].to_vec(),
                         Some(false),
+                        None,
+                        None,
                     )?,
                     columns: Column::table_from_bytes(
                         header_data[0xC4..0x1FD].into(),
                         Some(column_names),
+                        &mut column_diagnostics,
                     )?,
                     // Computed Attributes
                     records_per_block: max(
@@ -366,11 +410,7 @@ impl Header {
                         % min(512, LittleEndian::read_u16(&header_data[0x4E..0x50])))
                         as u64,
                     version: Version::V23B,
-                    filepath: if let Some(file) = filepath {
-                        file.as_ref().to_string()
-                    } else {
-                        String::new()
-                    },
+                    filepath: filepath.clone(),
                     ..Header::default()
                 }
             }
@@ -385,25 +425,27 @@ impl Header {
                     reuse_deleted_space: header_data[0x4A] == 0,
                     multiuser_reread_active: false,
                     // Embedded Structures
-                    indexes: Index::table_from_bytes(header_data[0xB0..0x1D0].into())?,
+                    indexes: Index::table_from_bytes(
+                        &header_data[0xB0..0x1D0],
+                        &mut index_diagnostics,
+                    )?,
                     file_root_name: string_from_bytes(
                         &header_data[0x2D0..0x2E0].to_vec(),
                         Some(false),
+                        None,
+                        None,
                     )?,
                     columns: Column::table_from_bytes(
                         header_data[0x2E0..0xAD8].into(),
                         Some(column_names),
+                        &mut column_diagnostics,
                     )?,
                     // Computed Attributes
                     records_per_block: LittleEndian::read_u16(&header_data[0x98..0x9A]) as u64,
                     fill_bytes_per_block: (512
                         % min(512u16, LittleEndian::read_u16(&header_data[0x9A..0x9C])))
                         as u64,
-                    filepath: if let Some(file) = filepath {
-                        file.as_ref().to_string()
-                    } else {
-                        String::new()
-                    },
+                    filepath: filepath.clone(),
                     version: Version::V30,
                     // V3 Attributes
                     _compression_type: match header_data[0x1F] {
@@ -431,20 +473,42 @@ impl Header {
                     header_data.len()
                 )));
             }
-        })
+        };
+
+        let (index_base, column_base): (u64, u64) = match header.version {
+            Version::V23B => (0x64, 0xC4),
+            _ => (0xB0, 0x2E0),
+        };
+
+        header.diagnostics = index_diagnostics
+            .into_iter()
+            .map(|diagnostic| diagnostic._relative_to(&filepath, index_base))
+            .chain(
+                column_diagnostics
+                    .into_iter()
+                    .map(|diagnostic| diagnostic._relative_to(&filepath, column_base)),
+            )
+            .collect();
+
+        Ok(header)
     }
 
     pub fn from_path(filepath: &str) -> PyResult<Header> {
+        Header::open(filepath, &OpenOptions::default())
+    }
+
+    pub fn open(filepath: &str, options: &OpenOptions) -> PyResult<Header> {
         // 1 - Ensure the provided path is actually a table
         //     - If it's not, return Header::default()
         // 2 - Try to find the table's tag file
         //     - Read the column names if it's found
+        //     - Otherwise fall back to its `.FD` / `.DEF` file
         // 3 - Call `get_header_bytes` with the validated table path
         // 4 - Form up like Voltron
 
         let table_path = path_from_string(filepath, Some(true));
 
-        let (column_names, header_data) = if table_path.is_file()
+        let (tag_path, header_data) = if table_path.is_file()
             && table_path.exists()
             && cl_eq(
                 table_path
@@ -455,14 +519,12 @@ impl Header {
                 "dat",
             ) {
             (
-                match TagFile::find_tags_for_table(&table_path) {
-                    Ok(Some(val)) => TagFile::from_filepath(&val)?.tags,
-                    _ => Vec::new(),
-                },
+                TagFile::find_tags_for_table(&table_path, &options.tag_paths, options.tag_depth)
+                    .unwrap_or_default(),
                 Header::_get_header_bytes_from(filepath)?,
             )
         } else {
-            (Vec::new(), Vec::new())
+            (None, Vec::new())
         };
 
         if header_data.is_empty() {
@@ -472,12 +534,78 @@ impl Header {
             )));
         }
 
-        Header::from_bytes(&header_data, column_names, Some(filepath))
+        let encoding: Encoding = options.encoding_for(&table_path);
+        let mut definition_path: Option<String> = None;
+        let mut definition_diagnostic: Option<Diagnostic> = None;
+
+        let column_names: Vec<String> = match &tag_path {
+            Some(path) => TagFile::from_filepath(path)?.tags,
+            None => match TableDefinition::find_for_table(
+                &table_path,
+                &options.tag_paths,
+                options.tag_depth,
+            ) {
+                Some(path) => match TableDefinition::from_path(&path, &encoding) {
+                    Ok(definition) => {
+                        definition_path = Some(path);
+                        definition.column_names()
+                    }
+                    // An unreadable definition leaves the columns unnamed
+                    Err(error) => {
+                        definition_diagnostic = Some(Diagnostic::new(
+                            DiagnosticKind::Table,
+                            &path,
+                            0,
+                            format!("unreadable table definition: {}", error),
+                        ));
+                        Vec::new()
+                    }
+                },
+                None => Vec::new(),
+            },
+        };
+
+        let mut header = Header::from_bytes(&header_data, column_names, Some(filepath))?;
+
+        header.tag_path = tag_path;
+        header.definition_path = definition_path;
+        header.diagnostics.extend(definition_diagnostic);
+
+        if options.strict {
+            Diagnostic::ensure_none(&header.diagnostics)?;
+        }
+
+        Ok(header)
     }
 
     // </editor-fold desc="// 'Public' Methods ...">
 }
 
+#[pymethods]
+impl Header {
+    #[new]
+    /// Read the header of the table file at `filepath`
+    fn py_new(filepath: &str) -> PyResult<Header> {
+        Header::from_path(filepath)
+    }
+
+    #[getter]
+    /// The "version" of the header's structure
+    fn get_version(&self) -> String {
+        self.version.to_string()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
 // </editor-fold desc="// Header ...">
 
 // <editor-fold desc="// Tests ...">
@@ -12,16 +12,23 @@ use caseless::compatibility_caseless_match_str as cl_eq;
 use gluesql::core::data::{Row, Schema, Value};
 use gluesql::core::result::Result as SqlResult;
 // use prettytable::{Cell, Row as PrintableRow, Table as PrettyTable};
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
 use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
 // Crate-Level Imports
-use crate::enums::{DataType, Version};
+use crate::dbapi::{namedtuple_type, py_from_row, row_as_dict};
+use crate::enums::{DataType, DeletedRecords, Encoding, RowFormat, Version};
 use crate::exceptions::NotSupportedError;
+use crate::options::OpenOptions;
+use crate::sql::StorageError;
 use crate::utils::{
-    bytes_from_file, date_from_bytes, float_from_bcd_bytes, int_from_bcd_bytes, string_from_bytes,
+    bytes_from_file, date_from_bytes, float_from_bcd_bytes, int_from_bcd_bytes,
+    string_from_bytes_with,
 };
+use crate::{iif, AttrIndexSliceOrItem, InterruptFlag, ValueOrSlice};
 pub use header::Header;
 
 // <editor-fold desc="// TableRowIterator ...">
@@ -31,34 +38,77 @@ pub struct TableRowIterator {
     /// The table being iterated over
     table: DataFlexTable,
     index: u32,
+    /// The flag used to cancel the iteration
+    /// from outside the iterating thread
+    interrupt: Option<InterruptFlag>,
 }
 
 impl Iterator for TableRowIterator {
     type Item = SqlResult<(usize, Row)>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index as u64 > self.table.len() {
-            return None;
-        }
+        loop {
+            if self.index as u64 >= self.table.len() {
+                return None;
+            }
 
-        self.index += 1;
+            if let Some(flag) = &self.interrupt {
+                if flag.is_set() {
+                    // Exhaust the iterator so that
+                    // the error is only reported once
+                    self.index = u32::MAX;
+                    return Some(Err(StorageError::Interrupted.into()));
+                }
+            }
 
-        match self.table.nth_record(self.index) {
-            Ok(row) => Some(SqlResult::Ok((self.index as usize, row))),
-            Err(_) => None,
+            self.index += 1;
+
+            return match self.table._read_record(self.index as u64) {
+                Ok(Some(row)) => Some(SqlResult::Ok((self.index as usize, row))),
+                // Skip over deleted records
+                Ok(None) => continue,
+                Err(error) => {
+                    let reason: String = format!(
+                        "record {} of '{}': {}",
+                        self.index, self.table.header.file_root_name, error
+                    );
+
+                    // Exhaust the iterator so that
+                    // the error is only reported once
+                    self.index = u32::MAX;
+                    Some(Err(StorageError::Unreadable(reason).into()))
+                }
+            };
         }
     }
 }
 
+impl TableRowIterator {
+    /// Make the iterator stop with an error as
+    /// soon as the supplied flag has been set
+    pub fn with_interrupt(mut self, interrupt: InterruptFlag) -> Self {
+        self.interrupt = Some(interrupt);
+        self
+    }
+}
+
 // </editor-fold desc="// TableRowIterator ...">
 
 // <editor-fold desc="// DataFlexTable ...">
 
 #[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+#[pyo3(text_signature = "(table_path: str)")]
 /// A structured representation of a DataFlex table file
 pub struct DataFlexTable {
+    #[pyo3(get)]
     /// The table's header data
     pub header: Header,
+    /// The options the table was opened with
+    pub options: OpenOptions,
+    /// The Python type records are returned as
+    /// when the table is accessed directly
+    pub row_format: RowFormat,
 }
 
 unsafe impl Send for DataFlexTable {}
@@ -89,24 +139,13 @@ impl DataFlexTable {
     // <editor-fold desc="// 'Private' Methods ...">
 
     pub(crate) fn _as_pretty_table(&self) -> String {
-        todo!()
+        self.header._as_pretty_table()
     }
 
-    pub(crate) fn nth_record_bytes<I: Into<i64>>(&self, record_number: I) -> PyResult<Vec<u8>> {
-        let record_number: i64 = record_number.into();
-
+    /// The offset at which the specified record starts
+    fn _record_offset(&self, record_number: i64) -> PyResult<u64> {
         let header = &self.header;
 
-        let record_number: i64 = if record_number < 0i64 {
-            header.record_count as i64 + record_number
-        } else {
-            record_number
-        };
-
-        if record_number < 0i64 || record_number > header.record_count as i64 {
-            return Err(PyIndexError::new_err(""));
-        }
-
         // TODO: Update this to behave properly for tables that have fill bytes
         if header.fill_bytes_per_block > 0 {
             panic!()
@@ -114,19 +153,59 @@ impl DataFlexTable {
 
         // Calculate the starting offset of the requested
         // record depending on the table version
-        let start: u64 = match header.version {
+        Ok(match header.version {
             Version::V23B => 512i64 + (header.record_length as i64 * record_number),
             Version::V30 => 3072i64 + (header.record_length as i64 * record_number),
             Version::Unknown => {
                 return Err(NotSupportedError::new_err("Unsupported table format!"));
             }
-        } as u64;
+        } as u64)
+    }
+
+    pub(crate) fn nth_record_bytes<I: Into<i64>>(&self, record_number: I) -> PyResult<Vec<u8>> {
+        let record_number: i64 = record_number.into();
+
+        let header = &self.header;
+
+        let record_number: i64 = if record_number < 0i64 {
+            header.record_count as i64 + record_number
+        } else {
+            record_number
+        };
+
+        if record_number < 0i64 || record_number > header.record_count as i64 {
+            return Err(PyIndexError::new_err(""));
+        }
 
+        let start: u64 = self._record_offset(record_number)?;
         let end: u64 = start + header.record_length as u64;
 
         bytes_from_file(&header.filepath, Some(start), Some(end))
     }
 
+    /// The numbers of the records a scan of the table produces (i.e. every
+    /// record, less any deleted records being skipped), found in a single
+    /// read of the table's record data
+    pub(crate) fn _record_numbers(&self) -> PyResult<Vec<u64>> {
+        let record_count: u64 = self.header.record_count;
+        let record_length: usize = self.header.record_length as usize;
+
+        if record_count == 0
+            || record_length == 0
+            || self.options.deleted_records == DeletedRecords::Include
+        {
+            return Ok((1..=record_count).collect());
+        }
+
+        let start: u64 = self._record_offset(1)?;
+        let end: u64 = start + record_count * record_length as u64;
+
+        Ok(live_record_numbers(
+            &bytes_from_file(&self.header.filepath, Some(start), Some(end))?,
+            record_length,
+        ))
+    }
+
     pub(crate) fn record_from_bytes<B: AsRef<[u8]>>(&self, record_data: B) -> PyResult<Row> {
         let record_data: &[u8] = record_data.as_ref();
 
@@ -140,7 +219,15 @@ impl DataFlexTable {
                 let data = &record_data[start..end];
 
                 match col.data_type {
-                    DataType::Ascii => Value::Str(string_from_bytes(data, Some(false)).unwrap()),
+                    DataType::Ascii => Value::Str(
+                        string_from_bytes_with(
+                            data,
+                            false,
+                            &self.options.encoding,
+                            &self.options.whitespace,
+                        )
+                        .unwrap(),
+                    ),
                     DataType::Int => Value::I64(int_from_bcd_bytes(data, Some(true)).unwrap()),
                     DataType::Float => {
                         Value::F64(float_from_bcd_bytes(data, Some(col.decimal_points)).unwrap())
@@ -152,7 +239,15 @@ impl DataFlexTable {
                     // The first two bytes of TEXT and BINARY fields are actually
                     // a u16 integer denoting how much of the field's allotted
                     // length is actually "populated"
-                    DataType::Text => Value::Str(string_from_bytes(data, Some(true)).unwrap()),
+                    DataType::Text => Value::Str(
+                        string_from_bytes_with(
+                            data,
+                            true,
+                            &self.options.encoding,
+                            &self.options.whitespace,
+                        )
+                        .unwrap(),
+                    ),
                     // `gluesql` doesn't currently support Binary / BLOB types
                     _ => Value::Null,
                     // data[2..][..LittleEndian::read_u16(&data[..2]) as usize].to_vec()
@@ -161,6 +256,48 @@ impl DataFlexTable {
             .collect::<Vec<Value>>()))
     }
 
+    /// Read a single record, returning `None` if the
+    /// record has been deleted and deleted records
+    /// are being skipped
+    pub(crate) fn _read_record(&self, record_number: u64) -> PyResult<Option<Row>> {
+        let data = self.nth_record_bytes(record_number as i64)?;
+
+        // Deleted records are zero-filled until they're reused
+        if self.options.deleted_records == DeletedRecords::Skip
+            && data.iter().all(|byte| *byte == 0)
+        {
+            return Ok(None);
+        }
+
+        self.record_from_bytes(&data).map(Some)
+    }
+
+    /// The names of the table's columns (as read from its tag file)
+    pub(crate) fn _column_names(&self) -> Vec<String> {
+        self.header
+            .columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect()
+    }
+
+    /// Convert a record into the Python type named by the table's
+    /// `row_format`, using `row_type` as the named-tuple class
+    pub(crate) fn _py_from_record(
+        &self,
+        py: Python,
+        row: &Row,
+        row_type: &PyObject,
+    ) -> PyResult<PyObject> {
+        let values: PyObject = py_from_row(py, row)?;
+        let values: &PyTuple = values.cast_as::<PyTuple>(py)?;
+
+        match self.row_format {
+            RowFormat::NamedTuple => row_type.call1(py, values),
+            RowFormat::Dict => row_as_dict(py, &self._column_names(), values),
+        }
+    }
+
     // </editor-fold desc="// 'Private' Methods ...">
 
     // <editor-fold desc="// Public Methods ...">
@@ -177,6 +314,7 @@ impl DataFlexTable {
         TableRowIterator {
             table: self,
             index: 0u32,
+            interrupt: None,
         }
     }
 
@@ -185,8 +323,17 @@ impl DataFlexTable {
     }
 
     pub fn from_path<P: AsRef<str>>(table_path: P) -> PyResult<DataFlexTable> {
+        DataFlexTable::open(table_path, &OpenOptions::default())
+    }
+
+    pub fn open<P: AsRef<str>>(table_path: P, options: &OpenOptions) -> PyResult<DataFlexTable> {
         Ok(DataFlexTable {
-            header: Header::from_path(table_path.as_ref())?,
+            header: Header::open(table_path.as_ref(), options)?,
+            options: OpenOptions {
+                encoding: options.encoding_for(table_path.as_ref()),
+                ..options.clone()
+            },
+            row_format: RowFormat::default(),
         })
     }
 
@@ -226,20 +373,265 @@ impl DataFlexTable {
     // </editor-fold desc="// Public Methods ...">
 }
 
+#[pymethods]
+impl DataFlexTable {
+    #[new]
+    /// Open the table file at `table_path`
+    fn py_new(table_path: &str) -> PyResult<DataFlexTable> {
+        DataFlexTable::from_path(table_path)
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// The number of records in the table (not counting deleted
+    /// records unless the table was opened with `deleted=include`)
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self._record_numbers()?.len())
+    }
+
+    /// Get one or more records by position, following Python's sequence
+    /// conventions: positions start at 0, negative indexes count back from
+    /// the last record, and slices produce a list of records. Deleted
+    /// records aren't counted (unless the table was opened with
+    /// `deleted=include`), so use `record()` to get a record by its
+    /// DataFlex record number.
+    fn __getitem__(
+        &self,
+        py: Python,
+        key: AttrIndexSliceOrItem<isize>,
+    ) -> PyResult<ValueOrSlice<PyObject>> {
+        let record_numbers: Vec<u64> = self._record_numbers()?;
+        let row_type: PyObject = namedtuple_type(py, &self._column_names())?;
+
+        let read = |record_number: u64| -> PyResult<PyObject> {
+            self._py_from_record(py, &self.nth_record(record_number as i64)?, &row_type)
+        };
+
+        match key {
+            AttrIndexSliceOrItem::Index(index) | AttrIndexSliceOrItem::Item(index) => {
+                let count: isize = record_numbers.len() as isize;
+                let position: isize = iif!(index < 0, count + index, index);
+
+                if position < 0 || position >= count {
+                    return Err(PyIndexError::new_err("table index out of range"));
+                }
+
+                Ok(ValueOrSlice::Value(read(
+                    record_numbers[position as usize],
+                )?))
+            }
+            AttrIndexSliceOrItem::Slice(slice) => {
+                let indices = slice.indices(record_numbers.len() as _)?;
+                let mut rows: Vec<PyObject> = Vec::new();
+                let mut index: isize = indices.start;
+
+                while (indices.step > 0 && index < indices.stop)
+                    || (indices.step < 0 && index > indices.stop)
+                {
+                    rows.push(read(record_numbers[index as usize])?);
+                    index += indices.step;
+                }
+
+                Ok(ValueOrSlice::Slice(rows))
+            }
+            AttrIndexSliceOrItem::Name(_) => Err(PyTypeError::new_err(
+                "records are indexed by position, not by name",
+            )),
+        }
+    }
+
+    #[pyo3(text_signature = "($self, record_number: int) -> Any")]
+    /// Get a record by its DataFlex record number (i.e. starting
+    /// at 1). Negative record numbers count back from the table's
+    /// last record.
+    fn record(&self, py: Python, record_number: isize) -> PyResult<PyObject> {
+        let record_count: u64 = self.header.record_count;
+        let row_type: PyObject = namedtuple_type(py, &self._column_names())?;
+
+        let record_number: u64 = match record_number_from_index(record_number, record_count) {
+            Some(record_number) => record_number,
+            None => {
+                return Err(PyIndexError::new_err(format!(
+                    "record number {} is out of range (1 - {})",
+                    record_number, record_count
+                )))
+            }
+        };
+
+        match self._read_record(record_number)? {
+            Some(row) => self._py_from_record(py, &row, &row_type),
+            None => Err(PyIndexError::new_err(format!(
+                "record number {} has been deleted",
+                record_number
+            ))),
+        }
+    }
+
+    /// Iterate over the table's records, reading each
+    /// record only as it's reached
+    fn __iter__(slf: PyRef<Self>) -> PyResult<RecordIterator> {
+        Ok(RecordIterator {
+            row_type: namedtuple_type(slf.py(), &slf._column_names())?,
+            table: (*slf).clone(),
+            next: 1,
+        })
+    }
+
+    #[getter]
+    /// The Python type records are returned as
+    /// (either "namedtuple" or "dict")
+    fn get_row_format(&self) -> String {
+        self.row_format.to_string()
+    }
+
+    #[setter]
+    fn set_row_format(&mut self, row_format: &str) -> PyResult<()> {
+        self.row_format = match row_format.to_lowercase().as_str() {
+            "namedtuple" => RowFormat::NamedTuple,
+            "dict" => RowFormat::Dict,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown row format '{}' (expected 'namedtuple' or 'dict')",
+                    row_format
+                )))
+            }
+        };
+
+        Ok(())
+    }
+
+    #[getter]
+    /// The encoding of the text stored in the table
+    fn get_encoding(&self) -> String {
+        self.options.encoding.to_string()
+    }
+
+    #[setter]
+    fn set_encoding(&mut self, encoding: &str) -> PyResult<()> {
+        self.options.encoding = Encoding::from_name(encoding)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown encoding '{}'", encoding)))?;
+
+        Ok(())
+    }
+}
+
 // </editor-fold desc="// DataFlexTable ...">
 
+// <editor-fold desc="// RecordIterator ...">
+
+#[pyclass(module = "ferroflex.structs")]
+/// A lazy Python iterator over the records in a DataFlex table
+pub struct RecordIterator {
+    /// The table being iterated over
+    table: DataFlexTable,
+    /// The named-tuple class records are returned as
+    row_type: PyObject,
+    /// The number of the next record to read
+    next: u64,
+}
+
+unsafe impl Send for RecordIterator {}
+
+#[pymethods]
+impl RecordIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        while self.next <= self.table.header.record_count {
+            let record_number: u64 = self.next;
+
+            self.next += 1;
+
+            if let Some(row) = self.table._read_record(record_number)? {
+                return Ok(Some(self.table._py_from_record(
+                    py,
+                    &row,
+                    &self.row_type,
+                )?));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+// </editor-fold desc="// RecordIterator ...">
+
+// <editor-fold desc="// Helpers ...">
+
+/// The (1-based) numbers of the records in `data` (a table's contiguous
+/// record data) that haven't been deleted (i.e. zero-filled)
+fn live_record_numbers(data: &[u8], record_length: usize) -> Vec<u64> {
+    data.chunks_exact(record_length)
+        .enumerate()
+        .filter(|(_, record)| record.iter().any(|byte| *byte != 0))
+        .map(|(idx, _)| idx as u64 + 1)
+        .collect()
+}
+
+/// Resolve a (possibly negative) Python index into a DataFlex
+/// record number, where record numbers start at 1 and -1 is
+/// the last record in the table
+fn record_number_from_index(index: isize, record_count: u64) -> Option<u64> {
+    let record_number: i128 = if index < 0 {
+        record_count as i128 + 1 + index as i128
+    } else {
+        index as i128
+    };
+
+    if record_number < 1 || record_number > record_count as i128 {
+        return None;
+    }
+
+    Some(record_number as u64)
+}
+
+// </editor-fold desc="// Helpers ...">
+
 // <editor-fold desc="// Tests ...">
 
 #[cfg(test)]
 mod tests {
     #![allow(unused_imports)]
-    use super::DataFlexTable;
+    use super::{live_record_numbers, record_number_from_index, DataFlexTable};
 
     #[test]
     /// Test that the `DataFlexTable` structure correctly gets rows
     fn gets_rows() {
         todo!()
     }
+
+    #[test]
+    /// Test that Python indexes resolve to the right record numbers
+    fn resolves_record_numbers() {
+        assert_eq!(record_number_from_index(1, 10), Some(1));
+        assert_eq!(record_number_from_index(10, 10), Some(10));
+        assert_eq!(record_number_from_index(-1, 10), Some(10));
+        assert_eq!(record_number_from_index(-10, 10), Some(1));
+        assert_eq!(record_number_from_index(0, 10), None);
+        assert_eq!(record_number_from_index(11, 10), None);
+        assert_eq!(record_number_from_index(-11, 10), None);
+    }
+
+    #[test]
+    /// Test that deleted (zero-filled) records aren't counted
+    fn finds_live_records() {
+        let data: Vec<u8> = [[1u8, 0], [0, 0], [0, 2], [0, 0]].concat();
+
+        assert_eq!(live_record_numbers(&data, 2), vec![1, 3]);
+        assert_eq!(live_record_numbers(&data[..5], 2), vec![1]);
+        assert!(live_record_numbers(&[], 2).is_empty());
+    }
 }
 
 // </editor-fold desc="// Tests ...">
@@ -3,13 +3,17 @@
 // Standard Library Imports
 use std::fmt;
 use std::fmt::Formatter;
-use std::iter::IntoIterator;
+use std::fs;
+use std::iter::{self, IntoIterator};
 use std::ops::Index as Indexable;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::slice::SliceIndex;
 
 // Third-Party Imports
+use caseless::compatibility_caseless_match_str as cl_eq;
 use prettytable::{Cell as PrettyCell, Row as PrettyRow, Table as PrettyTable};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
 use pyo3::PyResult;
 use serde::{Deserialize, Serialize};
 
@@ -25,11 +29,15 @@ pub type TagCollection = Vec<String>;
 // <editor-fold desc="// Tag File ...">
 
 #[derive(Clone, Debug, Default, Eq, Ord, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "ferroflex.structs")]
+#[pyo3(text_signature = "(filepath: str)")]
 /// A structured representation of a field segment's
 /// definition in the header of a DataFlex table file
 pub struct TagFile {
+    #[pyo3(get)]
     /// The file's absolute on-disk path
     pub filepath: String,
+    #[pyo3(get)]
     /// The column names contained by the file
     pub tags: TagCollection,
 }
@@ -85,14 +93,31 @@ impl TagFile {
 
     // <editor-fold desc="// 'Public' Methods ...">
 
-    pub fn find_tags_for_table(table_path: &Path) -> PyResult<Option<String>> {
-        let tag_path = table_path.with_extension("tag");
-
-        if !tag_path.exists() || !tag_path.is_file() {
-            return Ok(None);
+    pub fn new<T: AsRef<str>>(filepath: T, tags: TagCollection) -> TagFile {
+        TagFile {
+            filepath: filepath.as_ref().to_string(),
+            tags,
         }
+    }
 
-        // TODO: Implement limited recursive ancestor search for MIA tag files
+    /// Find the tag file for the table at `table_path`, returning the path
+    /// of the first match. Tag files next to the table win, then each of
+    /// the supplied search paths is checked (in order), and finally up to
+    /// `ancestor_depth` of the table's ancestor directories are checked.
+    /// Relative search paths are resolved against the table's directory
+    /// (and each searched ancestor), so a search path of `tags` with an
+    /// `ancestor_depth` of 1 finds both `./tags/` and `../tags/`. File
+    /// names are matched without regard to case, since tag files usually
+    /// come from DOS machines.
+    pub fn find_tags_for_table(
+        table_path: &Path,
+        search_paths: &[PathBuf],
+        ancestor_depth: usize,
+    ) -> PyResult<Option<String>> {
+        let tag_path = match find_companion_file(table_path, "tag", search_paths, ancestor_depth) {
+            Some(path) => path,
+            None => return Ok(None),
+        };
 
         Ok(Some(string_from_path(
             &tag_path,
@@ -118,6 +143,34 @@ impl TagFile {
         })
     }
 
+    /// Encode the file's column names (in column order) as
+    /// CRLF-terminated lines, the way DataFlex writes them
+    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
+        let mut data: Vec<u8> = Vec::new();
+
+        for tag in self.tags.iter() {
+            // Tag files are split on whitespace when they're read
+            if tag.is_empty() || !tag.is_ascii() || tag.contains(|chr: char| chr.is_whitespace()) {
+                return Err(PyValueError::new_err(format!(
+                    "'{}' isn't a valid column name",
+                    tag
+                )));
+            }
+
+            data.extend_from_slice(tag.as_bytes());
+            data.extend_from_slice(b"\r\n");
+        }
+
+        Ok(data)
+    }
+
+    /// Write the file's column names to its `filepath`
+    pub fn save(&self) -> PyResult<()> {
+        fs::write(&self.filepath, self.to_bytes()?)?;
+
+        Ok(())
+    }
+
     pub fn generate_column_names<T: AsRef<str>>(
         column_count: u8,
         known_columns: Option<Vec<T>>,
@@ -141,20 +194,185 @@ impl TagFile {
     // </editor-fold desc="// 'Public' Methods ...">
 }
 
+#[pymethods]
+impl TagFile {
+    #[new]
+    /// Read the tag file at `filepath`
+    fn py_new(filepath: &str) -> PyResult<TagFile> {
+        TagFile::from_filepath(filepath)
+    }
+
+    #[pyo3(name = "save", text_signature = "($self)")]
+    /// Write the file's column names to its `filepath`
+    fn py_save(&self) -> PyResult<()> {
+        self.save()
+    }
+
+    #[staticmethod]
+    #[pyo3(
+        name = "find_tags_for_table",
+        text_signature = "(table_path: str, search_paths: List[str] = [], ancestor_depth: int = 0) -> Optional[str]"
+    )]
+    /// Find the tag file for the table at `table_path`, searching the
+    /// table's directory, then `search_paths`, then up to `ancestor_depth`
+    /// of the table's ancestor directories
+    fn py_find_tags_for_table(
+        table_path: &str,
+        search_paths: Option<Vec<String>>,
+        ancestor_depth: Option<usize>,
+    ) -> PyResult<Option<String>> {
+        let search_paths: Vec<PathBuf> = search_paths
+            .unwrap_or_default()
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+
+        TagFile::find_tags_for_table(
+            Path::new(table_path),
+            &search_paths,
+            ancestor_depth.unwrap_or_default(),
+        )
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __repr__(&self) -> String {
+        self._as_pretty_table()
+    }
+
+    /// Render the structure as a "pretty" table
+    fn __str__(&self) -> String {
+        self._as_pretty_table()
+    }
+}
+
 // </editor-fold desc="// Tag File ...">
 
+// <editor-fold desc="// Helpers ...">
+
+/// Find the file sharing the name of the table at `table_path` but with
+/// the supplied `extension` (see `TagFile::find_tags_for_table` for the
+/// order in which directories are searched)
+pub(crate) fn find_companion_file(
+    table_path: &Path,
+    extension: &str,
+    search_paths: &[PathBuf],
+    ancestor_depth: usize,
+) -> Option<PathBuf> {
+    let file_name: String = format!("{}.{}", table_path.file_stem()?.to_str()?, extension);
+    let table_dir: &Path = table_path.parent().unwrap_or_else(|| Path::new(""));
+
+    let relative_paths = || search_paths.iter().filter(|dir| dir.is_relative());
+    let ancestors = table_dir
+        .ancestors()
+        .skip(1)
+        .take(ancestor_depth)
+        .flat_map(|ancestor| {
+            iter::once(ancestor.to_path_buf())
+                .chain(relative_paths().map(move |dir| ancestor.join(dir)))
+        });
+
+    iter::once(table_dir.to_path_buf())
+        .chain(search_paths.iter().map(|dir| table_dir.join(dir)))
+        .chain(ancestors)
+        .find_map(|dir| file_in_dir(&dir, &file_name))
+}
+
+/// Find the file in `dir` named `file_name` (ignoring case)
+fn file_in_dir(dir: &Path, file_name: &str) -> Option<PathBuf> {
+    let exact: PathBuf = dir.join(file_name);
+
+    if exact.is_file() {
+        return Some(exact);
+    }
+
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| cl_eq(name, file_name))
+        })
+        .min()
+}
+
+// </editor-fold desc="// Helpers ...">
+
 // <editor-fold desc="// Tests ...">
 
 #[cfg(test)]
 mod tests {
     #![allow(unused_imports)]
     use super::{TagCollection, TagFile};
+    use std::fs;
+    use std::path::PathBuf;
 
     #[test]
     /// Test that the `TagFile` structure behaves as expected
     fn gets_tag_files() {
         todo!()
     }
+
+    #[test]
+    /// Test that tag files are found beside, near, and above their tables
+    fn finds_tag_files() {
+        let root: PathBuf =
+            std::env::temp_dir().join(format!("ferroflex-tags-{}", std::process::id()));
+        let data_dir: PathBuf = root.join("site").join("data");
+
+        fs::create_dir_all(&data_dir).unwrap();
+        fs::create_dir_all(root.join("site").join("tags")).unwrap();
+
+        let find = |table: &str, search_paths: &[&str], depth: usize| {
+            let search_paths: Vec<PathBuf> = search_paths.iter().map(PathBuf::from).collect();
+
+            TagFile::find_tags_for_table(&data_dir.join(table), &search_paths, depth)
+                .unwrap()
+                .map(|path| {
+                    path.strip_prefix(root.to_str().unwrap())
+                        .unwrap()
+                        .to_string()
+                })
+        };
+
+        fs::write(data_dir.join("CUSTOMER.TAG"), "NUMBER\r\nNAME\r\n").unwrap();
+        fs::write(root.join("site").join("tags").join("ordhead.tag"), "").unwrap();
+        fs::write(root.join("site").join("vendor.tag"), "").unwrap();
+
+        assert_eq!(
+            find("customer.dat", &[], 0).as_deref(),
+            Some("/site/data/CUSTOMER.TAG")
+        );
+        assert_eq!(find("ordhead.dat", &[], 0), None);
+        assert_eq!(
+            find("ordhead.dat", &["../tags"], 0).as_deref(),
+            Some("/site/data/../tags/ordhead.tag")
+        );
+        assert_eq!(
+            find("ordhead.dat", &["tags"], 1).as_deref(),
+            Some("/site/tags/ordhead.tag")
+        );
+        assert_eq!(find("vendor.dat", &[], 0), None);
+        assert_eq!(
+            find("vendor.dat", &[], 1).as_deref(),
+            Some("/site/vendor.tag")
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    /// Test that tag files are written as CRLF-terminated names
+    fn writes_tag_files() {
+        let tags = TagFile::new("x.tag", vec!["NUMBER".to_string(), "NAME".to_string()]);
+
+        assert_eq!(tags.to_bytes().unwrap(), b"NUMBER\r\nNAME\r\n".to_vec());
+        assert!(TagFile::new("x.tag", vec!["FIRST NAME".to_string()])
+            .to_bytes()
+            .is_err());
+    }
 }
 
 // </editor-fold desc="// Tests ...">
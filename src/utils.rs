@@ -15,12 +15,14 @@ use byteorder::{ByteOrder, LittleEndian};
 use itertools::zip;
 use num::traits::abs;
 use pyo3;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use pyo3_chrono::chrono::{Duration, NaiveDate};
 use pyo3_chrono::NaiveDate as PyDate;
 
 // Crate-Level Imports
+use crate::enums::{Encoding, Whitespace};
 use crate::exceptions::{BCDDecodingError, TextFieldDecodingError};
 
 // <editor-fold desc="// Component Registration ...">
@@ -116,6 +118,111 @@ pub fn bytes_from_file<P: AsRef<str>, U: Into<u64>>(
     Ok(file_bytes.to_vec())
 }
 
+/// Decode the value of an ASCII or TEXT field using the specified `encoding`
+/// and `whitespace` handling (i.e. the Rust-side equivalent of `string_from_bytes`)
+pub fn string_from_bytes_with(
+    data: &[u8],
+    text_field: bool,
+    encoding: &Encoding,
+    whitespace: &Whitespace,
+) -> PyResult<String> {
+    // The first two bytes of TEXT fields are actually
+    // a u16 integer denoting the number of the field's
+    // allotted bytes that are actually "occupied"
+    if text_field && data.len() < 4 {
+        return Err(TextFieldDecodingError::new_err("Too few bytes!"));
+    }
+
+    let (text_length, data) = if text_field {
+        (LittleEndian::read_u16(&data[..2]), &data[2..])
+    } else {
+        (0u16, data)
+    };
+
+    // Raw and preserved values keep every "occupied" byte
+    if *encoding == Encoding::Raw || *whitespace == Whitespace::Preserve {
+        let data: &[u8] = if text_field {
+            data.get(..text_length as usize).ok_or_else(|| {
+                TextFieldDecodingError::new_err(format!(
+                    "Expected {} bytes but the field only holds {}!",
+                    text_length,
+                    data.len()
+                ))
+            })?
+        } else {
+            data
+        };
+
+        let text: String = data.iter().map(|val| encoding.decode_byte(*val)).collect();
+
+        // DataFlex pads values with trailing NULs or spaces and
+        // ignores that padding when comparing them, so it's the
+        // only part of a preserved value that gets dropped
+        return Ok(match encoding {
+            Encoding::Raw => text,
+            _ => text.trim_end_matches([' ', '\0']).into(),
+        });
+    }
+
+    let text: String = data
+        .iter()
+        .filter(|entry| (&8u8 < *entry && *entry < &14u8) || (&31u8 < *entry))
+        .map(|val| encoding.decode_byte(*val))
+        .collect::<String>()
+        .trim()
+        .into();
+
+    if text_field && text.chars().count() as u16 != text_length {
+        return Err(TextFieldDecodingError::new_err(format!(
+            "Expected a {}-character but actually got {} characters!",
+            text_length,
+            text.chars().count()
+        )));
+    }
+
+    Ok(text)
+}
+
+/// Encode `text` as a NUL-padded field of exactly `length` bytes, leaving
+/// room for at least one trailing NUL (i.e. the "inverse" of a non-TEXT
+/// `string_from_bytes` call)
+pub fn bytes_from_string<T: AsRef<str>>(text: T, length: usize) -> PyResult<Vec<u8>> {
+    bytes_from_string_with(text, length, &Encoding::Latin1)
+}
+
+/// Encode `text` as a NUL-padded field of exactly `length` bytes
+/// using the specified `encoding`
+pub fn bytes_from_string_with<T: AsRef<str>>(
+    text: T,
+    length: usize,
+    encoding: &Encoding,
+) -> PyResult<Vec<u8>> {
+    let text: &str = text.as_ref();
+
+    let mut data: Vec<u8> = text
+        .chars()
+        .map(|chr| encoding.encode_char(chr))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "'{}' contains characters that can't be encoded as {}",
+                text, encoding
+            ))
+        })?;
+
+    if data.len() >= length {
+        return Err(PyValueError::new_err(format!(
+            "'{}' is too long (the maximum length is {} characters)",
+            text,
+            length.saturating_sub(1)
+        )));
+    }
+
+    data.resize(length, 0u8);
+
+    Ok(data)
+}
+
 // </editor-fold desc="// Helpers ...">
 
 // <editor-fold desc="// Python Functions ...">
@@ -246,42 +353,29 @@ pub fn date_from_bytes(data: &[u8]) -> PyResult<Option<PyDate>> {
 }
 
 #[pyfunction]
-#[pyo3(text_signature = "(data: bytes, text_field: bool = False) -> str")]
+#[pyo3(
+    text_signature = "(data: bytes, text_field: bool = False, encoding: str = 'latin-1', preserve_whitespace: bool = False) -> str"
+)]
 /// Get the value of an ASCII or TEXT field from a DataFlex table file.
-pub fn string_from_bytes(data: &[u8], text_field: Option<bool>) -> PyResult<String> {
-    // Ensure that `text_field` has a usable value
-    let text_field: bool = text_field.unwrap_or(false);
-
-    // The first two bytes of TEXT fields are actually
-    // a u16 integer denoting the number of the field's
-    // allotted bytes that are actually "occupied"
-    if text_field && data.len() < 4 {
-        return Err(TextFieldDecodingError::new_err("Too few bytes!"));
-    }
+pub fn string_from_bytes(
+    data: &[u8],
+    text_field: Option<bool>,
+    encoding: Option<&str>,
+    preserve_whitespace: Option<bool>,
+) -> PyResult<String> {
+    let encoding: Encoding = match encoding {
+        None => Encoding::default(),
+        Some(name) => Encoding::from_name(name)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown encoding '{}'", name)))?,
+    };
 
-    let (text_length, data) = if text_field {
-        (LittleEndian::read_u16(&data[..2]), &data[2..])
+    let whitespace: Whitespace = if preserve_whitespace.unwrap_or(false) {
+        Whitespace::Preserve
     } else {
-        (0u16, data)
+        Whitespace::Trim
     };
 
-    let text: String = data
-        .iter()
-        .filter(|entry| ((&8u8 < *entry && *entry < &14u8) || (&31u8 < *entry)))
-        .map(|val| char::from(*val))
-        .collect::<String>()
-        .trim()
-        .into();
-
-    if text_field && text.len() as u16 != text_length {
-        return Err(TextFieldDecodingError::new_err(format!(
-            "Expected a {}-character but actually got {} characters!",
-            text_length,
-            text.len()
-        )));
-    }
-
-    Ok(text)
+    string_from_bytes_with(data, text_field.unwrap_or(false), &encoding, &whitespace)
 }
 
 #[pyfunction]
@@ -312,10 +406,11 @@ pub fn bytes_from_file_py(
 mod tests {
     #[allow(unused_imports)]
     use super::{
-        bytes_from_file, bytes_from_file_py, date_from_bytes, float_from_bcd_bytes,
-        int_from_bcd_bytes, int_from_packed_bcd, int_from_unpacked_bcd, path_from_string,
-        string_from_bytes, string_from_path,
+        bytes_from_file, bytes_from_file_py, bytes_from_string_with, date_from_bytes,
+        float_from_bcd_bytes, int_from_bcd_bytes, int_from_packed_bcd, int_from_unpacked_bcd,
+        path_from_string, string_from_bytes, string_from_bytes_with, string_from_path,
     };
+    use crate::enums::{Encoding, Whitespace};
     use pyo3::PyResult;
 
     #[test]
@@ -410,6 +505,79 @@ mod tests {
         // string_from_bytes
         todo!()
     }
+
+    #[test]
+    /// Test that ASCII and TEXT fields are decoded and
+    /// encoded with the requested code page
+    fn gets_strings_in_code_pages() -> PyResult<()> {
+        let data: &[u8] = b"Jos\x82 Mu\xA4oz\x00\x00";
+
+        assert_eq!(
+            string_from_bytes_with(data, false, &Encoding::Cp437, &Whitespace::Trim)?,
+            "José Muñoz"
+        );
+        assert_eq!(
+            string_from_bytes_with(data, false, &Encoding::Cp850, &Whitespace::Trim)?,
+            "José Muñoz"
+        );
+        assert_eq!(
+            string_from_bytes_with(
+                b"\x93caf\xE9\x94",
+                false,
+                &Encoding::Windows1252,
+                &Whitespace::Trim
+            )?,
+            "“café”"
+        );
+        assert_eq!(
+            bytes_from_string_with("José Muñoz", 12, &Encoding::Cp850)?,
+            data
+        );
+        assert!(bytes_from_string_with("“café”", 12, &Encoding::Cp437).is_err());
+
+        // Raw values keep every byte so they can be written back out unchanged
+        let raw: String =
+            string_from_bytes_with(b" ab\x01\x00", false, &Encoding::Raw, &Whitespace::Trim)?;
+
+        assert_eq!(raw, " ab\u{1}\u{0}");
+        assert_eq!(
+            bytes_from_string_with(&raw, 6, &Encoding::Raw)?,
+            b" ab\x01\x00\x00"
+        );
+        assert_eq!(
+            string_from_bytes_with(
+                b"\x03\x00ab\x82\x00",
+                true,
+                &Encoding::Raw,
+                &Whitespace::Trim
+            )?,
+            "ab\u{82}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    /// Test that preserved values only lose their trailing padding
+    fn preserves_whitespace() -> PyResult<()> {
+        let preserve = |data: &[u8], text_field: bool| {
+            string_from_bytes_with(data, text_field, &Encoding::Latin1, &Whitespace::Preserve)
+        };
+
+        assert_eq!(preserve(b"  A1\x07 \x00\x00", false)?, "  A1\u{7}");
+        assert_eq!(preserve(b"\x04\x00 \tab\x00\x00", true)?, " \tab");
+        assert_eq!(
+            string_from_bytes_with(
+                b"  A1\x07 \x00",
+                false,
+                &Encoding::Latin1,
+                &Whitespace::Trim
+            )?,
+            "A1"
+        );
+
+        Ok(())
+    }
 }
 
 // </editor-fold desc="// Tests ...">